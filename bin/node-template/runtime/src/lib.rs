@@ -217,6 +217,8 @@ impl pallet_grandpa::Config for Runtime {
 
 parameter_types! {
     pub const MinimumPeriod: u64 = SLOT_DURATION / 2;
+    pub const MedianWindow: u32 = 5;
+    pub const MaxPastDriftMillis: u64 = u64::max_value();
 }
 
 impl pallet_timestamp::Config for Runtime {
@@ -224,6 +226,8 @@ impl pallet_timestamp::Config for Runtime {
     type Moment = u64;
     type OnTimestampSet = Aura;
     type MinimumPeriod = MinimumPeriod;
+    type MedianWindow = MedianWindow;
+    type MaxPastDriftMillis = MaxPastDriftMillis;
     type WeightInfo = ();
 }
 
@@ -400,12 +400,16 @@ impl pallet_transaction_payment::Config for Runtime {
 
 parameter_types! {
     pub const MinimumPeriod: Moment = SLOT_DURATION / 2;
+    pub const MedianWindow: u32 = 5;
+    pub const MaxPastDriftMillis: u64 = u64::max_value();
 }
 
 impl pallet_timestamp::Config for Runtime {
     type Moment = Moment;
     type OnTimestampSet = Babe;
     type MinimumPeriod = MinimumPeriod;
+    type MedianWindow = MedianWindow;
+    type MaxPastDriftMillis = MaxPastDriftMillis;
     type WeightInfo = pallet_timestamp::weights::SubstrateWeight<Runtime>;
 }
 
@@ -756,6 +760,7 @@ impl pallet_contracts::Config for Runtime {
     type Randomness = RandomnessCollectiveFlip;
     type Currency = Balances;
     type Event = Event;
+    type Call = Call;
     type RentPayment = ();
     type SignedClaimHandicap = SignedClaimHandicap;
     type TombstoneDeposit = TombstoneDeposit;
@@ -781,6 +786,7 @@ impl pallet_sudo::Config for Runtime {
 parameter_types! {
     pub const SessionDuration: BlockNumber = EPOCH_DURATION_IN_SLOTS as _;
     pub const ImOnlineUnsignedPriority: TransactionPriority = TransactionPriority::max_value();
+    pub const MaxNetworkStateAddresses: u32 = 100;
     /// We prioritize im-online heartbeats over election solution submission.
     pub const StakingUnsignedPriority: TransactionPriority = TransactionPriority::max_value() / 2;
 }
@@ -851,6 +857,7 @@ impl pallet_im_online::Config for Runtime {
     type SessionDuration = SessionDuration;
     type ReportUnresponsiveness = Offences;
     type UnsignedPriority = ImOnlineUnsignedPriority;
+    type MaxNetworkStateAddresses = MaxNetworkStateAddresses;
     type WeightInfo = pallet_im_online::weights::SubstrateWeight<Runtime>;
 }
 
@@ -863,6 +870,7 @@ impl pallet_offences::Config for Runtime {
     type Event = Event;
     type IdentificationTuple = pallet_session::historical::IdentificationTuple<Self>;
     type OnOffenceHandler = Staking;
+    type OnReport = ();
     type WeightSoftLimit = OffencesWeightSoftLimit;
 }
 
@@ -998,6 +1006,8 @@ impl pallet_lottery::Config for Runtime {
     type MaxCalls = MaxCalls;
     type ValidateCall = Lottery;
     type MaxGenerateRandom = MaxGenerateRandom;
+    type WinnerSelector = Lottery;
+    type EmptyLotteryHandler = ();
     type WeightInfo = pallet_lottery::weights::SubstrateWeight<Runtime>;
 }
 
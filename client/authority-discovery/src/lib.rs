@@ -60,6 +60,12 @@ pub struct WorkerConfig {
     ///
     /// By default this is set to 10 minutes.
     pub max_query_interval: Duration,
+    /// The maximum number of authorities whose addresses, found on the DHT, are inserted into the
+    /// address cache per iteration of the worker loop. Any further ones are kept for the following
+    /// iterations. A value of 0 is treated as 1.
+    ///
+    /// By default this is set to 100.
+    pub max_cache_inserts_per_call: usize,
 }
 
 impl Default for WorkerConfig {
@@ -78,6 +84,7 @@ impl Default for WorkerConfig {
             // comparing `authority_discovery_authority_addresses_requested_total` and
             // `authority_discovery_dht_event_received`.
             max_query_interval: Duration::from_secs(10 * 60),
+            max_cache_inserts_per_call: 100,
         }
     }
 }
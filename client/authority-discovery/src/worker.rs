@@ -26,10 +26,11 @@ use std::collections::{HashMap, HashSet};
 use std::convert::TryInto;
 use std::marker::PhantomData;
 use std::sync::Arc;
+use std::task::Poll;
 use std::time::Duration;
 
 use futures::channel::mpsc;
-use futures::{stream::Fuse, FutureExt, Stream, StreamExt};
+use futures::{stream::Fuse, Future, FutureExt, Stream, StreamExt};
 
 use addr_cache::AddrCache;
 use async_trait::async_trait;
@@ -122,6 +123,10 @@ pub struct Worker<Client, Network, Block, DhtEventStream> {
     in_flight_lookups: HashMap<libp2p::kad::record::Key, AuthorityId>,
 
     addr_cache: addr_cache::AddrCache,
+    /// Verified addresses found on the DHT, waiting to be inserted into `addr_cache`.
+    pending_cache_inserts: Vec<(AuthorityId, Vec<Multiaddr>)>,
+    /// Maximum number of `pending_cache_inserts` applied per loop iteration.
+    max_cache_inserts_per_call: usize,
 
     metrics: Option<Metrics>,
 
@@ -182,6 +187,8 @@ where
             pending_lookups: Vec::new(),
             in_flight_lookups: HashMap::new(),
             addr_cache,
+            pending_cache_inserts: Vec::new(),
+            max_cache_inserts_per_call: config.max_cache_inserts_per_call.max(1),
             role,
             metrics,
             phantom: PhantomData,
@@ -191,6 +198,7 @@ where
     /// Start the worker
     pub async fn run(mut self) {
         loop {
+            self.apply_pending_cache_inserts();
             self.start_new_lookups();
 
             futures::select! {
@@ -226,6 +234,9 @@ where
                         );
                     }
                 },
+                // Apply the next batch of addresses found on the DHT, even if no other event
+                // arrives in the meantime.
+                _ = pending_inserts_wakeup(!self.pending_cache_inserts.is_empty()).fuse() => {},
             }
         }
     }
@@ -340,6 +351,8 @@ where
             .collect();
 
         self.addr_cache.retain_ids(&authorities);
+        self.pending_cache_inserts
+            .retain(|(id, _addresses)| authorities.contains(id));
 
         authorities.shuffle(&mut thread_rng());
         self.pending_lookups = authorities;
@@ -543,19 +556,44 @@ where
             .collect();
 
         if !remote_addresses.is_empty() {
-            self.addr_cache.insert(authority_id, remote_addresses);
-            if let Some(metrics) = &self.metrics {
-                metrics.known_authorities_count.set(
-                    self.addr_cache
-                        .num_ids()
-                        .try_into()
-                        .unwrap_or(std::u64::MAX),
-                );
-            }
+            self.pending_cache_inserts
+                .push((authority_id, remote_addresses));
         }
         Ok(())
     }
 
+    /// Insert up to `max_cache_inserts_per_call` of the addresses found on the DHT into the address
+    /// cache, leaving the remaining ones for the next loop iteration.
+    fn apply_pending_cache_inserts(&mut self) {
+        if self.pending_cache_inserts.is_empty() {
+            return;
+        }
+
+        let remaining = self.pending_cache_inserts.split_off(
+            self.max_cache_inserts_per_call
+                .min(self.pending_cache_inserts.len()),
+        );
+        let batch = std::mem::replace(&mut self.pending_cache_inserts, remaining);
+        let applied = self
+            .addr_cache
+            .insert_many(batch, self.max_cache_inserts_per_call);
+        debug!(
+            target: LOG_TARGET,
+            "Inserted addresses of {} authorities into the cache, {} pending.",
+            applied,
+            self.pending_cache_inserts.len(),
+        );
+
+        if let Some(metrics) = &self.metrics {
+            metrics.known_authorities_count.set(
+                self.addr_cache
+                    .num_ids()
+                    .try_into()
+                    .unwrap_or(std::u64::MAX),
+            );
+        }
+    }
+
     /// Retrieve our public keys within the current and next authority set.
     //
     // A node might have multiple authority discovery keys within its keystore, e.g. an old one and
@@ -591,6 +629,26 @@ where
     }
 }
 
+/// Resolves after yielding to the executor once if `has_pending_inserts` is true, never resolves
+/// otherwise.
+///
+/// Used to wake up [`Worker::run`] while addresses found on the DHT still wait to be inserted
+/// into the address cache, without starving the other tasks of the executor.
+fn pending_inserts_wakeup(has_pending_inserts: bool) -> impl Future<Output = ()> {
+    let mut yielded = false;
+    futures::future::poll_fn(move |cx| {
+        if !has_pending_inserts {
+            return Poll::Pending;
+        }
+        if yielded {
+            return Poll::Ready(());
+        }
+        yielded = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    })
+}
+
 /// NetworkProvider provides [`Worker`] with all necessary hooks into the
 /// underlying Substrate networking. Using this trait abstraction instead of
 /// [`sc_network::NetworkService`] directly is necessary to unit test [`Worker`].
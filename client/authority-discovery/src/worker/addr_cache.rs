@@ -22,10 +22,20 @@ use std::collections::HashMap;
 use sc_network::PeerId;
 use sp_authority_discovery::AuthorityId;
 
+/// Changes to an [`AddrCache`] reported to its event listener.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) enum AddrCacheEvent {
+    /// Addresses of the given [`AuthorityId`] were inserted or replaced.
+    Inserted(AuthorityId),
+    /// [`AddrCache::retain_ids`] evicted `removed` authorities.
+    Retained { removed: usize },
+}
+
 /// Cache for [`AuthorityId`] -> [`Vec<Multiaddr>`] and [`PeerId`] -> [`AuthorityId`] mappings.
 pub(super) struct AddrCache {
     authority_id_to_addresses: HashMap<AuthorityId, Vec<Multiaddr>>,
     peer_id_to_authority_id: HashMap<PeerId, AuthorityId>,
+    event_listener: Option<Box<dyn Fn(AddrCacheEvent) + Send>>,
 }
 
 impl AddrCache {
@@ -33,12 +43,38 @@ impl AddrCache {
         AddrCache {
             authority_id_to_addresses: HashMap::new(),
             peer_id_to_authority_id: HashMap::new(),
+            event_listener: None,
+        }
+    }
+
+    /// Sets a listener called on every insert and on every [`AddrCache::retain_ids`], e.g. to
+    /// track discovery churn in metrics.
+    pub fn set_event_listener(&mut self, listener: Box<dyn Fn(AddrCacheEvent) + Send>) {
+        self.event_listener = Some(listener);
+    }
+
+    fn emit(&self, event: AddrCacheEvent) {
+        if let Some(listener) = &self.event_listener {
+            listener(event);
         }
     }
 
     /// Inserts the given [`AuthorityId`] and [`Vec<Multiaddr>`] pair for future lookups by
     /// [`AuthorityId`] or [`PeerId`].
-    pub fn insert(&mut self, authority_id: AuthorityId, mut addresses: Vec<Multiaddr>) {
+    pub fn insert(&mut self, authority_id: AuthorityId, addresses: Vec<Multiaddr>) {
+        self.insert_filtered(authority_id, addresses, |_| true)
+    }
+
+    /// Like [`AddrCache::insert`], but only keeps the addresses for which `keep` returns `true`.
+    ///
+    /// Nothing is inserted if no address is kept.
+    pub fn insert_filtered(
+        &mut self,
+        authority_id: AuthorityId,
+        mut addresses: Vec<Multiaddr>,
+        keep: impl Fn(&Multiaddr) -> bool,
+    ) {
+        addresses.retain(keep);
         if addresses.is_empty() {
             return;
         }
@@ -56,7 +92,56 @@ impl AddrCache {
         // Insert into `self.authority_id_to_addresses`.
         addresses.sort_unstable_by(|a, b| a.as_ref().cmp(b.as_ref()));
         self.authority_id_to_addresses
-            .insert(authority_id, addresses);
+            .insert(authority_id.clone(), addresses);
+
+        self.emit(AddrCacheEvent::Inserted(authority_id));
+    }
+
+    /// Inserts at most `max_per_call` of the given [`AuthorityId`] and [`Vec<Multiaddr>`] pairs,
+    /// in order, and returns how many of them were applied.
+    ///
+    /// This allows the caller to spread the insertion of large sets of entries across multiple
+    /// calls.
+    pub fn insert_many(
+        &mut self,
+        entries: Vec<(AuthorityId, Vec<Multiaddr>)>,
+        max_per_call: usize,
+    ) -> usize {
+        let mut applied = 0;
+        for (authority_id, addresses) in entries.into_iter().take(max_per_call) {
+            self.insert(authority_id, addresses);
+            applied += 1;
+        }
+        applied
+    }
+
+    /// Inserts all [`AuthorityId`] to [`Vec<Multiaddr>`] mappings of `other`, overwriting the
+    /// addresses of authorities known to both caches.
+    pub fn merge(&mut self, other: AddrCache) {
+        for (authority_id, addresses) in other.authority_id_to_addresses {
+            self.insert(authority_id, addresses);
+        }
+    }
+
+    /// Returns all [`AuthorityId`] to [`Vec<Multiaddr>`] mappings, sorted by [`AuthorityId`], e.g.
+    /// to persist the cache across restarts.
+    pub fn export(&self) -> Vec<(AuthorityId, Vec<Multiaddr>)> {
+        let mut entries = self
+            .authority_id_to_addresses
+            .iter()
+            .map(|(authority_id, addresses)| (authority_id.clone(), addresses.clone()))
+            .collect::<Vec<_>>();
+        entries.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+        entries
+    }
+
+    /// Creates a cache from mappings previously returned by [`AddrCache::export`].
+    pub fn import(entries: Vec<(AuthorityId, Vec<Multiaddr>)>) -> Self {
+        let mut cache = AddrCache::new();
+        for (authority_id, addresses) in entries {
+            cache.insert(authority_id, addresses);
+        }
+        cache
     }
 
     /// Returns the number of authority IDs in the cache.
@@ -77,6 +162,13 @@ impl AddrCache {
         self.peer_id_to_authority_id.get(peer_id)
     }
 
+    /// Returns the addresses of the [`AuthorityId`] the given [`PeerId`] belongs to.
+    pub fn get_addresses_by_peer_id(&self, peer_id: &PeerId) -> Option<&Vec<Multiaddr>> {
+        self.get_authority_id_by_peer_id(peer_id)
+            .and_then(|authority_id| self.get_addresses_by_authority_id(authority_id))
+            .filter(|addresses| !addresses.is_empty())
+    }
+
     /// Removes all [`PeerId`]s and [`Multiaddr`]s from the cache that are not related to the given
     /// [`AuthorityId`]s.
     pub fn retain_ids(&mut self, authority_ids: &Vec<AuthorityId>) {
@@ -88,6 +180,7 @@ impl AddrCache {
             .map(|entry| entry.0)
             .cloned()
             .collect::<Vec<AuthorityId>>();
+        let removed = authority_ids_to_remove.len();
 
         for authority_id_to_remove in authority_ids_to_remove {
             // Remove other entries from `self.authority_id_to_addresses`.
@@ -107,6 +200,8 @@ impl AddrCache {
                 }
             }
         }
+
+        self.emit(AddrCacheEvent::Retained { removed });
     }
 }
 
@@ -129,6 +224,7 @@ mod tests {
 
     use sp_authority_discovery::{AuthorityId, AuthorityPair};
     use sp_core::crypto::Pair;
+    use std::sync::{Arc, Mutex};
 
     #[derive(Clone, Debug)]
     struct TestAuthorityId(AuthorityId);
@@ -207,4 +303,243 @@ mod tests {
             .max_tests(10)
             .quickcheck(property as fn(_, _, _) -> TestResult)
     }
+
+    #[test]
+    fn resolves_addresses_by_peer_id() {
+        fn property(authority: (TestAuthorityId, TestMultiaddr)) -> TestResult {
+            let authority: (AuthorityId, Multiaddr) = ((authority.0).0, (authority.1).0);
+            let peer_id = peer_id_from_multiaddr(&authority.1).unwrap();
+
+            let mut cache = AddrCache::new();
+            assert_eq!(None, cache.get_addresses_by_peer_id(&peer_id));
+
+            cache.insert(authority.0.clone(), vec![authority.1.clone()]);
+
+            assert_eq!(
+                Some(&vec![authority.1.clone()]),
+                cache.get_addresses_by_peer_id(&peer_id),
+                "Expect `get_addresses_by_peer_id` to return addresses of the authority."
+            );
+
+            TestResult::passed()
+        }
+
+        QuickCheck::new()
+            .max_tests(10)
+            .quickcheck(property as fn(_) -> TestResult)
+    }
+
+    #[test]
+    fn merge_overwrites_overlapping_authorities() {
+        fn property(
+            first: (TestAuthorityId, TestMultiaddr),
+            second: (TestAuthorityId, TestMultiaddr, TestMultiaddr),
+            third: (TestAuthorityId, TestMultiaddr),
+        ) -> TestResult {
+            let first: (AuthorityId, Multiaddr) = ((first.0).0, (first.1).0);
+            let second: (AuthorityId, Multiaddr, Multiaddr) =
+                ((second.0).0, (second.1).0, (second.2).0);
+            let third: (AuthorityId, Multiaddr) = ((third.0).0, (third.1).0);
+
+            let mut cache = AddrCache::new();
+            cache.insert(first.0.clone(), vec![first.1.clone()]);
+            cache.insert(second.0.clone(), vec![second.1.clone()]);
+
+            let mut other = AddrCache::new();
+            other.insert(second.0.clone(), vec![second.2.clone()]);
+            other.insert(third.0.clone(), vec![third.1.clone()]);
+
+            cache.merge(other);
+
+            assert_eq!(3, cache.num_ids());
+            assert_eq!(
+                Some(&vec![first.1.clone()]),
+                cache.get_addresses_by_authority_id(&first.0),
+                "Expect `merge` to keep authorities only known to the merged into cache."
+            );
+            assert_eq!(
+                Some(&vec![second.2.clone()]),
+                cache.get_addresses_by_authority_id(&second.0),
+                "Expect `merge` to prefer the addresses of the merged cache."
+            );
+            assert_eq!(
+                Some(&third.0),
+                cache.get_authority_id_by_peer_id(&peer_id_from_multiaddr(&third.1).unwrap()),
+                "Expect `merge` to insert authorities only known to the merged cache."
+            );
+
+            TestResult::passed()
+        }
+
+        QuickCheck::new()
+            .max_tests(10)
+            .quickcheck(property as fn(_, _, _) -> TestResult)
+    }
+
+    #[test]
+    fn insert_filtered_drops_rejected_addresses() {
+        fn property(authority: TestAuthorityId, public: TestMultiaddr) -> TestResult {
+            let authority: AuthorityId = authority.0;
+            let public: Multiaddr = public.0;
+            let peer_id = peer_id_from_multiaddr(&public).unwrap();
+            let loopback = "/ip6/::1/tcp/30333"
+                .parse::<Multiaddr>()
+                .unwrap()
+                .with(Protocol::P2p(peer_id.into()));
+
+            let is_not_loopback = |a: &Multiaddr| {
+                !a.iter().any(|p| match p {
+                    Protocol::Ip4(ip) => ip.is_loopback(),
+                    Protocol::Ip6(ip) => ip.is_loopback(),
+                    _ => false,
+                })
+            };
+
+            let mut cache = AddrCache::new();
+
+            cache.insert_filtered(authority.clone(), vec![loopback.clone()], is_not_loopback);
+            assert_eq!(
+                0,
+                cache.num_ids(),
+                "Expect `insert_filtered` to skip authorities without kept addresses."
+            );
+
+            cache.insert_filtered(
+                authority.clone(),
+                vec![loopback, public.clone()],
+                is_not_loopback,
+            );
+            assert_eq!(
+                Some(&vec![public]),
+                cache.get_addresses_by_authority_id(&authority),
+                "Expect `insert_filtered` to only store the public address."
+            );
+
+            TestResult::passed()
+        }
+
+        QuickCheck::new()
+            .max_tests(10)
+            .quickcheck(property as fn(_, _) -> TestResult)
+    }
+
+    #[test]
+    fn insert_many_applies_at_most_max_per_call() {
+        fn property(
+            first: (TestAuthorityId, TestMultiaddr),
+            second: (TestAuthorityId, TestMultiaddr),
+            third: (TestAuthorityId, TestMultiaddr),
+            fourth: (TestAuthorityId, TestMultiaddr),
+            fifth: (TestAuthorityId, TestMultiaddr),
+        ) -> TestResult {
+            let entries = vec![first, second, third, fourth, fifth]
+                .into_iter()
+                .map(|(authority, address)| (authority.0, vec![address.0]))
+                .collect::<Vec<_>>();
+
+            let mut cache = AddrCache::new();
+            let applied = cache.insert_many(entries.clone(), 2);
+
+            assert_eq!(2, applied);
+            assert_eq!(2, cache.num_ids());
+            for (authority, addresses) in &entries[..2] {
+                assert_eq!(
+                    Some(addresses),
+                    cache.get_addresses_by_authority_id(authority),
+                    "Expect `insert_many` to insert the first entries."
+                );
+            }
+            for (authority, _) in &entries[2..] {
+                assert_eq!(None, cache.get_addresses_by_authority_id(authority));
+            }
+
+            TestResult::passed()
+        }
+
+        QuickCheck::new()
+            .max_tests(10)
+            .quickcheck(property as fn(_, _, _, _, _) -> TestResult)
+    }
+
+    #[test]
+    fn import_of_export_round_trips() {
+        fn property(
+            first: (TestAuthorityId, TestMultiaddr, TestMultiaddr),
+            second: (TestAuthorityId, TestMultiaddr),
+        ) -> TestResult {
+            let first: (AuthorityId, Multiaddr, Multiaddr) =
+                ((first.0).0, (first.1).0, (first.2).0);
+            let second: (AuthorityId, Multiaddr) = ((second.0).0, (second.1).0);
+
+            let mut cache = AddrCache::new();
+            cache.insert(first.0.clone(), vec![first.1.clone(), first.2.clone()]);
+            cache.insert(second.0.clone(), vec![second.1.clone()]);
+
+            let exported = cache.export();
+            assert!(
+                exported.windows(2).all(|w| w[0].0 < w[1].0),
+                "Expect `export` to be sorted by `AuthorityId`."
+            );
+
+            let imported = AddrCache::import(exported.clone());
+            assert_eq!(exported, imported.export());
+            for address in &[first.1, first.2, second.1] {
+                let peer_id = peer_id_from_multiaddr(address).unwrap();
+                assert_eq!(
+                    cache.get_authority_id_by_peer_id(&peer_id),
+                    imported.get_authority_id_by_peer_id(&peer_id),
+                    "Expect `import` to restore the `PeerId` lookups."
+                );
+            }
+            for authority_id in &[first.0, second.0] {
+                assert_eq!(
+                    cache.get_addresses_by_authority_id(authority_id),
+                    imported.get_addresses_by_authority_id(authority_id),
+                    "Expect `import` to restore the `AuthorityId` lookups."
+                );
+            }
+
+            TestResult::passed()
+        }
+
+        QuickCheck::new()
+            .max_tests(10)
+            .quickcheck(property as fn(_, _) -> TestResult)
+    }
+
+    #[test]
+    fn event_listener_observes_insert_and_retain() {
+        fn property(
+            first: (TestAuthorityId, TestMultiaddr),
+            second: (TestAuthorityId, TestMultiaddr),
+        ) -> TestResult {
+            let first: (AuthorityId, Multiaddr) = ((first.0).0, (first.1).0);
+            let second: (AuthorityId, Multiaddr) = ((second.0).0, (second.1).0);
+
+            let events = Arc::new(Mutex::new(Vec::new()));
+            let mut cache = AddrCache::new();
+            let recorded = events.clone();
+            cache.set_event_listener(Box::new(move |event| recorded.lock().unwrap().push(event)));
+
+            cache.insert(first.0.clone(), vec![first.1.clone()]);
+            cache.insert(second.0.clone(), vec![second.1.clone()]);
+            cache.retain_ids(&vec![first.0.clone()]);
+
+            assert_eq!(
+                vec![
+                    AddrCacheEvent::Inserted(first.0),
+                    AddrCacheEvent::Inserted(second.0),
+                    AddrCacheEvent::Retained { removed: 1 },
+                ],
+                *events.lock().unwrap(),
+                "Expect the listener to observe both inserts and the eviction."
+            );
+
+            TestResult::passed()
+        }
+
+        QuickCheck::new()
+            .max_tests(10)
+            .quickcheck(property as fn(_, _) -> TestResult)
+    }
 }
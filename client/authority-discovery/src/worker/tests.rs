@@ -547,6 +547,7 @@ fn limit_number_of_addresses_added_to_cache_per_authority() {
     worker
         .handle_dht_value_found_event(vec![dht_event])
         .unwrap();
+    worker.apply_pending_cache_inserts();
     assert_eq!(
         MAX_ADDRESSES_PER_AUTHORITY,
         worker
@@ -604,6 +605,7 @@ fn do_not_cache_addresses_without_peer_id() {
     local_worker
         .handle_dht_value_found_event(vec![dht_event])
         .unwrap();
+    local_worker.apply_pending_cache_inserts();
 
     assert_eq!(
         Some(&vec![multiaddr_with_peer_id]),
@@ -614,6 +616,151 @@ fn do_not_cache_addresses_without_peer_id() {
     );
 }
 
+#[test]
+fn cache_inserts_are_spread_across_loop_iterations() {
+    let remote_key_store = KeyStore::new();
+    let remote_publics: Vec<AuthorityId> = (0..5)
+        .map(|_| {
+            block_on(remote_key_store.sr25519_generate_new(key_types::AUTHORITY_DISCOVERY, None))
+                .unwrap()
+                .into()
+        })
+        .collect();
+
+    let (_dht_event_tx, dht_event_rx) = channel(1);
+    let (_to_worker, from_service) = mpsc::channel(0);
+    let mut worker = Worker::new(
+        from_service,
+        Arc::new(TestApi {
+            authorities: remote_publics.clone(),
+        }),
+        Arc::new(TestNetwork::default()),
+        Box::pin(dht_event_rx),
+        Role::Discover,
+        None,
+        crate::WorkerConfig {
+            max_cache_inserts_per_call: 2,
+            ..Default::default()
+        },
+    );
+
+    block_on(worker.refill_pending_lookups_queue()).unwrap();
+    worker.start_new_lookups();
+
+    for remote_public in &remote_publics {
+        let address: Multiaddr = "/ip6/2001:db8:0:0:0:0:0:1/tcp/30333".parse().unwrap();
+        let dht_event = block_on(build_dht_event(
+            vec![address.with(multiaddr::Protocol::P2p(PeerId::random().into()))],
+            remote_public.clone(),
+            &remote_key_store,
+        ));
+        worker
+            .handle_dht_value_found_event(vec![dht_event])
+            .unwrap();
+    }
+
+    // Nothing is inserted while handling the events themselves.
+    assert_eq!(0, worker.addr_cache.num_ids());
+    assert_eq!(5, worker.pending_cache_inserts.len());
+
+    for (cached, pending) in vec![(2, 3), (4, 1), (5, 0)] {
+        worker.apply_pending_cache_inserts();
+        assert_eq!(cached, worker.addr_cache.num_ids());
+        assert_eq!(pending, worker.pending_cache_inserts.len());
+    }
+}
+
+#[test]
+fn pending_cache_inserts_are_applied_without_further_events() {
+    let remote_publics: Vec<AuthorityId> = (0..10)
+        .map(|_| {
+            block_on(KeyStore::new().sr25519_generate_new(key_types::AUTHORITY_DISCOVERY, None))
+                .unwrap()
+                .into()
+        })
+        .collect();
+    let address: Multiaddr = "/ip6/2001:db8:0:0:0:0:0:1/tcp/30333".parse().unwrap();
+    let address = address.with(multiaddr::Protocol::P2p(PeerId::random().into()));
+
+    let (_dht_event_tx, dht_event_rx) = channel(1);
+    let (mut to_worker, from_service) = mpsc::channel(1);
+    let mut worker = Worker::new(
+        from_service,
+        Arc::new(TestApi {
+            authorities: remote_publics.clone(),
+        }),
+        Arc::new(TestNetwork::default()),
+        Box::pin(dht_event_rx),
+        Role::Discover,
+        None,
+        crate::WorkerConfig {
+            max_cache_inserts_per_call: 1,
+            ..Default::default()
+        },
+    );
+    worker.pending_cache_inserts = remote_publics
+        .iter()
+        .map(|id| (id.clone(), vec![address.clone()]))
+        .collect();
+
+    let mut pool = LocalPool::new();
+    let _ = pool
+        .spawner()
+        .spawn_local_obj(worker.run().boxed_local().into());
+    pool.run_until_stalled();
+
+    pool.run_until(async {
+        let (sender, addresses) = futures::channel::oneshot::channel();
+        to_worker
+            .send(ServicetoWorkerMsg::GetAddressesByAuthorityId(
+                remote_publics.last().unwrap().clone(),
+                sender,
+            ))
+            .await
+            .expect("Channel has capacity of 1.");
+        assert_eq!(Some(vec![address]), addresses.await.unwrap());
+    });
+}
+
+#[test]
+fn refill_drops_pending_cache_inserts_of_former_authorities() {
+    let authority: AuthorityId =
+        block_on(KeyStore::new().sr25519_generate_new(key_types::AUTHORITY_DISCOVERY, None))
+            .unwrap()
+            .into();
+    let former_authority: AuthorityId =
+        block_on(KeyStore::new().sr25519_generate_new(key_types::AUTHORITY_DISCOVERY, None))
+            .unwrap()
+            .into();
+    let address: Multiaddr = "/ip6/2001:db8:0:0:0:0:0:1/tcp/30333".parse().unwrap();
+    let address = address.with(multiaddr::Protocol::P2p(PeerId::random().into()));
+
+    let (_dht_event_tx, dht_event_rx) = channel(1);
+    let (_to_worker, from_service) = mpsc::channel(0);
+    let mut worker = Worker::new(
+        from_service,
+        Arc::new(TestApi {
+            authorities: vec![authority.clone()],
+        }),
+        Arc::new(TestNetwork::default()),
+        Box::pin(dht_event_rx),
+        Role::Discover,
+        None,
+        Default::default(),
+    );
+    worker.pending_cache_inserts = vec![
+        (former_authority, vec![address.clone()]),
+        (authority.clone(), vec![address.clone()]),
+    ];
+
+    block_on(worker.refill_pending_lookups_queue()).unwrap();
+
+    assert_eq!(
+        vec![(authority, vec![address])],
+        worker.pending_cache_inserts
+    );
+}
+
 #[test]
 fn addresses_to_publish_adds_p2p() {
     let (_dht_event_tx, dht_event_rx) = channel(1000);
@@ -20,7 +20,7 @@
 
 // FIXME #1021 move this into sp-consensus
 
-use codec::Decode;
+use codec::{Decode, Encode};
 use futures::{
     channel::oneshot,
     future,
@@ -348,6 +348,19 @@ where
 
         let (block, storage_changes, proof) = block_builder.build()?.into_inner();
 
+        if let (Some(limit), Some(proof)) = (record_proof.limit(), proof.as_ref()) {
+            let size = proof.encoded_size();
+            if size > limit {
+                return Err(sp_blockchain::Error::Application(
+                    format!(
+                        "Recorded proof of {} bytes exceeds the limit of {} bytes",
+                        size, limit,
+                    )
+                    .into(),
+                ));
+            }
+        }
+
         self.metrics.report(|metrics| {
             metrics
                 .number_of_transactions
@@ -82,6 +82,29 @@ pub enum Error {
     GlobalLoggerError(#[from] sc_tracing::logging::Error),
 }
 
+impl Error {
+    /// A stable short name of the group of errors this error belongs to, e.g. to bucket errors in
+    /// structured logs or metrics.
+    pub fn category(&self) -> &'static str {
+        match self {
+            Error::Io(_) => "io",
+            Error::Service(_) | Error::Client(_) => "client",
+            Error::KeyStoreOperation | Error::KeyStorage(_) => "keystore",
+            Error::InvalidListenMultiaddress => "network",
+            Error::Cli(_)
+            | Error::Codec(_)
+            | Error::Input(_)
+            | Error::InvalidUri(_)
+            | Error::SignatureInvalidLength { .. }
+            | Error::KeyTypeInvalid
+            | Error::SignatureInvalid
+            | Error::HexDataConversion(_) => "input",
+            Error::Application(_) => "application",
+            Error::GlobalLoggerError(_) => "logging",
+        }
+    }
+}
+
 impl std::convert::From<&str> for Error {
     fn from(s: &str) -> Error {
         Error::Input(s.to_string())
@@ -99,3 +122,23 @@ impl std::convert::From<crypto::PublicError> for Error {
         Error::InvalidUri(e)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn errors_are_categorized() {
+        assert_eq!(
+            Error::Io(std::io::ErrorKind::NotFound.into()).category(),
+            "io"
+        );
+        assert_eq!(Error::from("bad").category(), "input");
+        assert_eq!(Error::KeyStoreOperation.category(), "keystore");
+        assert_eq!(Error::InvalidListenMultiaddress.category(), "network");
+        assert_eq!(
+            Error::Client(sp_blockchain::Error::Backend("db".into())).category(),
+            "client"
+        );
+    }
+}
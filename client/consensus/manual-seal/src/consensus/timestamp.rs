@@ -0,0 +1,135 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2020-2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Slot pre-digest provider driven by the timestamp inherent.
+
+use super::ConsensusDataProvider;
+use crate::Error;
+use codec::Encode;
+use sp_consensus::{slot_from_timestamp, BlockImportParams};
+use sp_consensus_aura::AURA_ENGINE_ID;
+use sp_consensus_slots::Slot;
+use sp_inherents::InherentData;
+use sp_runtime::{
+    generic::Digest,
+    traits::{Block as BlockT, DigestFor, DigestItemFor},
+};
+use sp_timestamp::TimestampInherentData;
+use std::marker::PhantomData;
+
+/// Provides a slot pre-digest computed from the timestamp inherent, in the shape Aura uses
+/// (`PreRuntime(AURA_ENGINE_ID, slot.encode())`).
+///
+/// Intended for runtimes that only need the current slot in the block header.
+pub struct TimestampConsensusDataProvider<Transaction> {
+    /// Slot duration in milliseconds.
+    slot_duration: u64,
+    _phantom: PhantomData<Transaction>,
+}
+
+impl<Transaction> TimestampConsensusDataProvider<Transaction> {
+    /// Create a new provider for the given slot duration in milliseconds.
+    ///
+    /// Fails if the slot duration is zero.
+    pub fn new(slot_duration: u64) -> Result<Self, Error> {
+        if slot_duration == 0 {
+            return Err(Error::StringError("Slot duration must be non-zero".into()));
+        }
+
+        Ok(Self {
+            slot_duration,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<B, Transaction> ConsensusDataProvider<B> for TimestampConsensusDataProvider<Transaction>
+where
+    B: BlockT,
+    Transaction: Send + Sync,
+{
+    type Transaction = Transaction;
+
+    fn create_digest(
+        &self,
+        _parent: &B::Header,
+        inherents: &InherentData,
+    ) -> Result<DigestFor<B>, Error> {
+        let timestamp = inherents.timestamp_inherent_data()?;
+        let slot = Slot::from(slot_from_timestamp(timestamp, self.slot_duration));
+
+        Ok(Digest {
+            logs: vec![DigestItemFor::<B>::PreRuntime(
+                AURA_ENGINE_ID,
+                slot.encode(),
+            )],
+        })
+    }
+
+    fn append_block_import(
+        &self,
+        _parent: &B::Header,
+        _params: &mut BlockImportParams<B, Self::Transaction>,
+        _inherents: &InherentData,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codec::Decode;
+    use sp_runtime::traits::Header as _;
+    use sp_timestamp::INHERENT_IDENTIFIER;
+    use substrate_test_runtime_client::runtime::{Block, Header};
+
+    #[test]
+    fn digest_carries_slot_of_timestamp() {
+        let provider = TimestampConsensusDataProvider::<()>::new(6_000).unwrap();
+        let parent = Header::new(
+            0,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        );
+        let mut inherents = InherentData::new();
+        inherents.put_data(INHERENT_IDENTIFIER, &15_000u64).unwrap();
+
+        let digest =
+            ConsensusDataProvider::<Block>::create_digest(&provider, &parent, &inherents).unwrap();
+
+        assert_eq!(digest.logs.len(), 1);
+        match &digest.logs[0] {
+            DigestItemFor::<Block>::PreRuntime(engine_id, data) => {
+                assert_eq!(*engine_id, AURA_ENGINE_ID);
+                assert_eq!(Slot::decode(&mut &data[..]).unwrap(), Slot::from(2));
+            }
+            item => panic!("unexpected digest item: {:?}", item),
+        }
+    }
+
+    #[test]
+    fn zero_slot_duration_is_rejected() {
+        assert!(matches!(
+            TimestampConsensusDataProvider::<()>::new(0),
+            Err(Error::StringError(_))
+        ));
+    }
+}
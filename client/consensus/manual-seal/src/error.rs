@@ -33,6 +33,7 @@ mod codes {
     pub const CONSENSUS_ERROR: i64 = 14_000;
     pub const INHERENTS_ERROR: i64 = 15_000;
     pub const BLOCKCHAIN_ERROR: i64 = 16_000;
+    pub const BLOCK_TOO_LARGE: i64 = 17_000;
     pub const UNKNOWN_ERROR: i64 = 20_000;
 }
 
@@ -59,6 +60,14 @@ pub enum Error {
     #[display(fmt = "Supplied parent_hash: {} doesn't exist in chain", _0)]
     #[from(ignore)]
     BlockNotFound(String),
+    /// The proposed block body exceeds the configured size limit
+    #[display(
+        fmt = "Block body of {} bytes exceeds the limit of {} bytes",
+        size,
+        limit
+    )]
+    #[from(ignore)]
+    BlockTooLarge { size: usize, limit: usize },
     /// Some string error
     #[display(fmt = "{}", _0)]
     #[from(ignore)]
@@ -84,6 +93,7 @@ impl Error {
             ConsensusError(_) => codes::CONSENSUS_ERROR,
             InherentError(_) => codes::INHERENTS_ERROR,
             BlockchainError(_) => codes::BLOCKCHAIN_ERROR,
+            BlockTooLarge { .. } => codes::BLOCK_TOO_LARGE,
             SendError(_) | Canceled(_) => codes::SERVER_SHUTTING_DOWN,
             _ => codes::UNKNOWN_ERROR,
         }
@@ -26,11 +26,14 @@ use sc_transaction_pool::txpool;
 use sp_blockchain::HeaderBackend;
 use sp_consensus::{
     import_queue::{BasicQueue, BoxBlockImport, CacheKeyId, Verifier},
-    BlockImport, BlockImportParams, BlockOrigin, Environment, ForkChoiceStrategy, Proposer,
-    SelectChain,
+    BlockImport, BlockImportParams, BlockImportParamsBuilder, BlockOrigin, Environment,
+    ForkChoiceStrategy, Proposer, SelectChain,
+};
+use sp_inherents::{InherentData, InherentDataProviders};
+use sp_runtime::{
+    traits::{Block as BlockT, UniqueSaturatedInto},
+    Justification,
 };
-use sp_inherents::InherentDataProviders;
-use sp_runtime::{traits::Block as BlockT, Justification};
 use std::{marker::PhantomData, sync::Arc};
 
 mod error;
@@ -44,13 +47,24 @@ pub use self::{
     consensus::ConsensusDataProvider,
     error::Error,
     finalize_block::{finalize_block, FinalizeBlockParams},
-    rpc::{CreatedBlock, EngineCommand},
+    rpc::{ChainHeads, CreatedBlock, EngineCommand},
     seal_block::{seal_block, SealBlockParams, MAX_PROPOSAL_DURATION},
 };
 use sp_api::{ProvideRuntimeApi, TransactionFor};
 
 /// The verifier for the manual seal engine; instantly finalizes.
-struct ManualSealVerifier;
+struct ManualSealVerifier {
+    /// fork choice strategy attached to every verified block.
+    fork_choice: ForkChoiceStrategy,
+}
+
+impl Default for ManualSealVerifier {
+    fn default() -> Self {
+        Self {
+            fork_choice: ForkChoiceStrategy::LongestChain,
+        }
+    }
+}
 
 impl<B: BlockT> Verifier<B> for ManualSealVerifier {
     fn verify(
@@ -60,11 +74,12 @@ impl<B: BlockT> Verifier<B> for ManualSealVerifier {
         justification: Option<Justification>,
         body: Option<Vec<B::Extrinsic>>,
     ) -> Result<(BlockImportParams<B, ()>, Option<Vec<(CacheKeyId, Vec<u8>)>>), String> {
-        let mut import_params = BlockImportParams::new(origin, header);
-        import_params.justification = justification;
-        import_params.body = body;
-        import_params.finalized = false;
-        import_params.fork_choice = Some(ForkChoiceStrategy::LongestChain);
+        let import_params = BlockImportParamsBuilder::new(origin, header)
+            .justification(justification)
+            .body(body)
+            .finalized(false)
+            .fork_choice(self.fork_choice)
+            .build();
 
         Ok((import_params, None))
     }
@@ -80,7 +95,26 @@ where
     Block: BlockT,
     Transaction: Send + Sync + 'static,
 {
-    BasicQueue::new(ManualSealVerifier, block_import, None, spawner, registry)
+    import_queue_with_fork_choice(block_import, spawner, registry, None)
+}
+
+/// Instantiate the import queue for the manual seal consensus engine, attaching the given
+/// fork choice strategy to imported blocks. Defaults to `ForkChoiceStrategy::LongestChain`.
+pub fn import_queue_with_fork_choice<Block, Transaction>(
+    block_import: BoxBlockImport<Block, Transaction>,
+    spawner: &impl sp_core::traits::SpawnNamed,
+    registry: Option<&Registry>,
+    fork_choice: Option<ForkChoiceStrategy>,
+) -> BasicQueue<Block, Transaction>
+where
+    Block: BlockT,
+    Transaction: Send + Sync + 'static,
+{
+    let verifier = match fork_choice {
+        Some(fork_choice) => ManualSealVerifier { fork_choice },
+        None => ManualSealVerifier::default(),
+    };
+    BasicQueue::new(verifier, block_import, None, spawner, registry)
 }
 
 /// Params required to start the instant sealing authorship task.
@@ -111,6 +145,21 @@ pub struct ManualSealParams<B: BlockT, BI, E, C: ProvideRuntimeApi<B>, A: txpool
 
     /// Provider for inherents to include in blocks.
     pub inherent_data_providers: InherentDataProviders,
+
+    /// Maximum size of the encoded block body; blocks exceeding it are not imported and the
+    /// seal fails with [`Error::BlockTooLarge`]. `None` disables the check.
+    pub max_block_body_bytes: Option<usize>,
+
+    /// Builds a justification for blocks finalized through [`EngineCommand::FinalizeBlock`]
+    /// without one. `None` finalizes such blocks without a justification.
+    pub justification_provider:
+        Option<Box<dyn Fn(&<B as BlockT>::Hash) -> Option<Justification> + Send + Sync>>,
+
+    /// Builds additional inherent data for every sealed block from its parent header. The
+    /// returned data supplements the one of `inherent_data_providers`. `None` only uses the
+    /// registered providers.
+    pub inherent_data_override:
+        Option<Box<dyn Fn(&<B as BlockT>::Header) -> InherentData + Send + Sync>>,
 }
 
 /// Params required to start the manual sealing authorship task.
@@ -139,6 +188,9 @@ pub struct InstantSealParams<B: BlockT, BI, E, C: ProvideRuntimeApi<B>, A: txpoo
 }
 
 /// Creates the background authorship task for the manual seal engine.
+///
+/// Every command the stream yields before it terminates is handled, including commands that
+/// were still buffered when all senders of the stream were dropped.
 pub async fn run_manual_seal<B, BI, CB, E, C, A, SC, CS>(
     ManualSealParams {
         mut block_import,
@@ -149,6 +201,9 @@ pub async fn run_manual_seal<B, BI, CB, E, C, A, SC, CS>(
         select_chain,
         inherent_data_providers,
         consensus_data_provider,
+        max_block_body_bytes,
+        justification_provider,
+        inherent_data_override,
         ..
     }: ManualSealParams<B, BI, E, C, A, SC, CS>,
 ) where
@@ -183,6 +238,9 @@ pub async fn run_manual_seal<B, BI, CB, E, C, A, SC, CS>(
                     select_chain: &select_chain,
                     block_import: &mut block_import,
                     inherent_data_provider: &inherent_data_providers,
+                    inherent_data_override: inherent_data_override.as_ref().map(|o| &**o),
+                    skip_inherents: false,
+                    max_block_body_bytes,
                     consensus_data_provider: consensus_data_provider.as_ref().map(|p| &**p),
                     pool: pool.clone(),
                     client: client.clone(),
@@ -194,6 +252,8 @@ pub async fn run_manual_seal<B, BI, CB, E, C, A, SC, CS>(
                 sender,
                 justification,
             } => {
+                let justification = justification
+                    .or_else(|| justification_provider.as_ref().and_then(|p| p(&hash)));
                 finalize_block(FinalizeBlockParams {
                     hash,
                     sender,
@@ -203,8 +263,24 @@ pub async fn run_manual_seal<B, BI, CB, E, C, A, SC, CS>(
                 })
                 .await
             }
+            EngineCommand::QueryHeads { mut sender } => {
+                let info = client.info();
+                let heads = ChainHeads {
+                    best_hash: info.best_hash,
+                    best_number: info.best_number.unique_saturated_into(),
+                    finalized_hash: info.finalized_hash,
+                    finalized_number: info.finalized_number.unique_saturated_into(),
+                };
+                rpc::send_result(&mut sender, Ok(heads))
+            }
+            EngineCommand::ClearPool { mut sender } => {
+                let removed = pool.validated_pool().clear().len();
+                rpc::send_result(&mut sender, Ok(removed))
+            }
         }
     }
+
+    log::info!("Manual seal command stream closed, shutting down");
 }
 
 /// runs the background authorship task for the instant seal engine.
@@ -256,6 +332,9 @@ pub async fn run_instant_seal<B, BI, CB, E, C, A, SC>(
         select_chain,
         consensus_data_provider,
         inherent_data_providers,
+        max_block_body_bytes: None,
+        justification_provider: None,
+        inherent_data_override: None,
     })
     .await
 }
@@ -266,12 +345,13 @@ mod tests {
     use sc_basic_authorship::ProposerFactory;
     use sc_client_api::BlockBackend;
     use sc_transaction_pool::{txpool::Options, BasicPool, RevalidationType};
-    use sp_consensus::ImportedAux;
+    use sp_consensus::{ImportResult, ImportedAux};
     use sp_inherents::InherentDataProviders;
     use sp_runtime::generic::BlockId;
     use sp_transaction_pool::{MaintainedTransactionPool, TransactionPool, TransactionSource};
     use substrate_test_runtime_client::{
-        AccountKeyring::*, DefaultTestClientBuilderExt, TestClientBuilder, TestClientBuilderExt,
+        AccountKeyring::*, Backend, DefaultTestClientBuilderExt, LongestChain, TestClient,
+        TestClientBuilder, TestClientBuilderExt,
     };
     use substrate_test_runtime_transaction_pool::{uxt, TestApi};
 
@@ -279,24 +359,77 @@ mod tests {
         Arc::new(TestApi::empty())
     }
 
-    const SOURCE: TransactionSource = TransactionSource::External;
+    type TestBlock = substrate_test_runtime_client::runtime::Block;
+    type TestPool = BasicPool<TestApi, TestBlock>;
+    type CommandSink = futures::channel::mpsc::Sender<EngineCommand<<TestBlock as BlockT>::Hash>>;
+    type CommandStream =
+        futures::channel::mpsc::Receiver<EngineCommand<<TestBlock as BlockT>::Hash>>;
 
-    #[tokio::test]
-    async fn instant_seal() {
-        let builder = TestClientBuilder::new();
-        let (client, select_chain) = builder.build_with_longest_chain();
-        let client = Arc::new(client);
-        let inherent_data_providers = InherentDataProviders::new();
-        let spawner = sp_core::testing::TaskExecutor::new();
+    /// Builds a test client with its select chain, a transaction pool on top of it and a channel
+    /// for sending commands to the authorship task.
+    pub(crate) fn setup_manual_seal() -> (
+        Arc<TestClient>,
+        LongestChain<Backend, TestBlock>,
+        Arc<TestPool>,
+        CommandSink,
+        CommandStream,
+    ) {
+        let (client, select_chain) = TestClientBuilder::new().build_with_longest_chain();
         let pool = Arc::new(BasicPool::with_revalidation_type(
             Options::default(),
             true.into(),
             api(),
             None,
             RevalidationType::Full,
-            spawner.clone(),
+            sp_core::testing::TaskExecutor::new(),
         ));
-        let env = ProposerFactory::new(spawner.clone(), client.clone(), pool.clone(), None);
+        let (sink, commands_stream) = futures::channel::mpsc::channel(1024);
+        (Arc::new(client), select_chain, pool, sink, commands_stream)
+    }
+
+    fn proposer_factory(
+        client: &Arc<TestClient>,
+        pool: &Arc<TestPool>,
+    ) -> ProposerFactory<TestPool, Backend, TestClient> {
+        ProposerFactory::new(
+            sp_core::testing::TaskExecutor::new(),
+            client.clone(),
+            pool.clone(),
+            None,
+        )
+    }
+
+    const SOURCE: TransactionSource = TransactionSource::External;
+
+    #[test]
+    fn verifier_uses_configured_fork_choice() {
+        let client = TestClientBuilder::new().build();
+        let genesis = client
+            .header(&BlockId::Number(0))
+            .unwrap()
+            .expect("genesis header exists");
+        let mut verifier = ManualSealVerifier {
+            fork_choice: ForkChoiceStrategy::Custom(false),
+        };
+
+        let (params, _) = Verifier::<substrate_test_runtime_client::runtime::Block>::verify(
+            &mut verifier,
+            BlockOrigin::NetworkBroadcast,
+            genesis,
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(params.fork_choice, Some(ForkChoiceStrategy::Custom(false)));
+        assert!(!params.finalized);
+    }
+
+    #[tokio::test]
+    async fn instant_seal() {
+        let (client, select_chain, pool, _sink, _) = setup_manual_seal();
+        let inherent_data_providers = InherentDataProviders::new();
+        let env = proposer_factory(&client, &pool);
         // this test checks that blocks are created as soon as transactions are imported into the pool.
         let (sender, receiver) = futures::channel::oneshot::channel();
         let mut sender = Arc::new(Some(sender));
@@ -323,6 +456,9 @@ mod tests {
             commands_stream,
             select_chain,
             inherent_data_providers,
+            max_block_body_bytes: None,
+            justification_provider: None,
+            inherent_data_override: None,
             consensus_data_provider: None,
         });
         std::thread::spawn(|| {
@@ -357,22 +493,10 @@ mod tests {
 
     #[tokio::test]
     async fn manual_seal_and_finalization() {
-        let builder = TestClientBuilder::new();
-        let (client, select_chain) = builder.build_with_longest_chain();
-        let client = Arc::new(client);
-        let inherent_data_providers = InherentDataProviders::new();
-        let spawner = sp_core::testing::TaskExecutor::new();
-        let pool = Arc::new(BasicPool::with_revalidation_type(
-            Options::default(),
-            true.into(),
-            api(),
-            None,
-            RevalidationType::Full,
-            spawner.clone(),
-        ));
-        let env = ProposerFactory::new(spawner.clone(), client.clone(), pool.clone(), None);
         // this test checks that blocks are created as soon as an engine command is sent over the stream.
-        let (mut sink, commands_stream) = futures::channel::mpsc::channel(1024);
+        let (client, select_chain, pool, mut sink, commands_stream) = setup_manual_seal();
+        let inherent_data_providers = InherentDataProviders::new();
+        let env = proposer_factory(&client, &pool);
         let future = run_manual_seal(ManualSealParams {
             block_import: client.clone(),
             env,
@@ -382,6 +506,9 @@ mod tests {
             select_chain,
             consensus_data_provider: None,
             inherent_data_providers,
+            max_block_body_bytes: None,
+            justification_provider: None,
+            inherent_data_override: None,
         });
         std::thread::spawn(|| {
             let mut rt = tokio::runtime::Runtime::new().unwrap();
@@ -434,24 +561,135 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn manual_seal_fork_blocks() {
-        let builder = TestClientBuilder::new();
-        let (client, select_chain) = builder.build_with_longest_chain();
-        let client = Arc::new(client);
+    async fn finalize_uses_justification_provider() {
+        let (client, select_chain, pool, mut sink, commands_stream) = setup_manual_seal();
         let inherent_data_providers = InherentDataProviders::new();
-        let pool_api = api();
-        let spawner = sp_core::testing::TaskExecutor::new();
-        let pool = Arc::new(BasicPool::with_revalidation_type(
-            Options::default(),
-            true.into(),
-            pool_api.clone(),
-            None,
-            RevalidationType::Full,
-            spawner.clone(),
-        ));
-        let env = ProposerFactory::new(spawner.clone(), client.clone(), pool.clone(), None);
+        let env = proposer_factory(&client, &pool);
+        let future = run_manual_seal(ManualSealParams {
+            block_import: client.clone(),
+            env,
+            client: client.clone(),
+            pool: pool.pool().clone(),
+            commands_stream,
+            select_chain,
+            consensus_data_provider: None,
+            inherent_data_providers,
+            max_block_body_bytes: None,
+            justification_provider: Some(Box::new(|_| Some(vec![1, 2, 3]))),
+            inherent_data_override: None,
+        });
+        std::thread::spawn(|| {
+            let mut rt = tokio::runtime::Runtime::new().unwrap();
+            // spawn the background authorship task
+            rt.block_on(future);
+        });
+        let (tx, rx) = futures::channel::oneshot::channel();
+        sink.send(EngineCommand::SealNewBlock {
+            parent_hash: None,
+            sender: Some(tx),
+            create_empty: true,
+            finalize: false,
+        })
+        .await
+        .unwrap();
+        let created_block = rx.await.unwrap().unwrap();
+
+        let (tx, rx) = futures::channel::oneshot::channel();
+        sink.send(EngineCommand::FinalizeBlock {
+            sender: Some(tx),
+            hash: created_block.hash,
+            justification: None,
+        })
+        .await
+        .unwrap();
+        assert_eq!(rx.await.unwrap().unwrap(), ());
+        // the provided justification is stored with the finalized block.
+        assert_eq!(
+            client
+                .justification(&BlockId::Hash(created_block.hash))
+                .unwrap(),
+            Some(vec![1, 2, 3])
+        );
+    }
+
+    const DUMMY_INHERENT: sp_inherents::InherentIdentifier = *b"dummyinh";
+
+    /// Digest provider that records the dummy inherent of the inherent data it is called with.
+    struct RecordingDataProvider(Arc<parking_lot::Mutex<Option<u64>>>);
+
+    impl ConsensusDataProvider<TestBlock> for RecordingDataProvider {
+        type Transaction = TransactionFor<TestClient, TestBlock>;
+
+        fn create_digest(
+            &self,
+            _parent: &<TestBlock as BlockT>::Header,
+            inherents: &InherentData,
+        ) -> Result<sp_runtime::traits::DigestFor<TestBlock>, Error> {
+            *self.0.lock() = inherents.get_data(&DUMMY_INHERENT)?;
+            Ok(Default::default())
+        }
+
+        fn append_block_import(
+            &self,
+            _parent: &<TestBlock as BlockT>::Header,
+            _params: &mut BlockImportParams<TestBlock, Self::Transaction>,
+            _inherents: &InherentData,
+        ) -> Result<(), Error> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn inherent_data_override_supplements_providers() {
+        let (client, select_chain, pool, mut sink, commands_stream) = setup_manual_seal();
+        let inherent_data_providers = InherentDataProviders::new();
+        let env = proposer_factory(&client, &pool);
+        let recorded = Arc::new(parking_lot::Mutex::new(None));
+        let future = run_manual_seal(ManualSealParams {
+            block_import: client.clone(),
+            env,
+            client: client.clone(),
+            pool: pool.pool().clone(),
+            commands_stream,
+            select_chain,
+            consensus_data_provider: Some(Box::new(RecordingDataProvider(recorded.clone()))),
+            inherent_data_providers,
+            max_block_body_bytes: None,
+            justification_provider: None,
+            inherent_data_override: Some(Box::new(|parent: &<TestBlock as BlockT>::Header| {
+                let mut data = InherentData::new();
+                data.put_data(DUMMY_INHERENT, &(parent.number + 1))
+                    .expect("data is empty; qed");
+                data
+            })),
+        });
+        std::thread::spawn(|| {
+            let mut rt = tokio::runtime::Runtime::new().unwrap();
+            // spawn the background authorship task
+            rt.block_on(future);
+        });
+        let (tx, rx) = futures::channel::oneshot::channel();
+        sink.send(EngineCommand::SealNewBlock {
+            parent_hash: None,
+            sender: Some(tx),
+            create_empty: true,
+            finalize: false,
+        })
+        .await
+        .unwrap();
+        rx.await.unwrap().unwrap();
+
+        // the block was proposed with the inherent built from its parent header.
+        assert_eq!(*recorded.lock(), Some(1));
+    }
+
+    #[tokio::test]
+    async fn manual_seal_fork_blocks() {
         // this test checks that blocks are created as soon as an engine command is sent over the stream.
-        let (mut sink, commands_stream) = futures::channel::mpsc::channel(1024);
+        let (client, select_chain, pool, mut sink, commands_stream) = setup_manual_seal();
+        let pool_api = pool.pool().validated_pool().api();
+        let inherent_data_providers = InherentDataProviders::new();
+        let env = proposer_factory(&client, &pool);
         let future = run_manual_seal(ManualSealParams {
             block_import: client.clone(),
             env,
@@ -461,6 +699,9 @@ mod tests {
             select_chain,
             consensus_data_provider: None,
             inherent_data_providers,
+            max_block_body_bytes: None,
+            justification_provider: None,
+            inherent_data_override: None,
         });
         std::thread::spawn(|| {
             let mut rt = tokio::runtime::Runtime::new().unwrap();
@@ -553,4 +794,335 @@ mod tests {
             .unwrap()
             .is_some())
     }
+
+    #[tokio::test]
+    async fn query_heads_reports_best_and_finalized() {
+        let (client, select_chain, pool, mut sink, commands_stream) = setup_manual_seal();
+        let inherent_data_providers = InherentDataProviders::new();
+        let env = proposer_factory(&client, &pool);
+        let future = run_manual_seal(ManualSealParams {
+            block_import: client.clone(),
+            env,
+            client: client.clone(),
+            pool: pool.pool().clone(),
+            commands_stream,
+            select_chain,
+            consensus_data_provider: None,
+            inherent_data_providers,
+            max_block_body_bytes: None,
+            justification_provider: None,
+            inherent_data_override: None,
+        });
+        std::thread::spawn(|| {
+            let mut rt = tokio::runtime::Runtime::new().unwrap();
+            // spawn the background authorship task
+            rt.block_on(future);
+        });
+
+        // seal two empty blocks on top of each other.
+        let mut hashes = Vec::new();
+        for _ in 0..2 {
+            let (tx, rx) = futures::channel::oneshot::channel();
+            sink.send(EngineCommand::SealNewBlock {
+                parent_hash: None,
+                sender: Some(tx),
+                create_empty: true,
+                finalize: false,
+            })
+            .await
+            .unwrap();
+            hashes.push(rx.await.unwrap().unwrap().hash);
+        }
+
+        // finalize the first one.
+        let (tx, rx) = futures::channel::oneshot::channel();
+        sink.send(EngineCommand::FinalizeBlock {
+            sender: Some(tx),
+            hash: hashes[0],
+            justification: None,
+        })
+        .await
+        .unwrap();
+        assert_eq!(rx.await.unwrap().unwrap(), ());
+
+        let (tx, rx) = futures::channel::oneshot::channel();
+        sink.send(EngineCommand::QueryHeads { sender: Some(tx) })
+            .await
+            .unwrap();
+        assert_eq!(
+            rx.await.unwrap().unwrap(),
+            ChainHeads {
+                best_hash: hashes[1],
+                best_number: 2,
+                finalized_hash: hashes[0],
+                finalized_number: 1,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn queued_commands_are_handled_before_shutdown() {
+        let (client, select_chain, pool, mut sink, commands_stream) = setup_manual_seal();
+        let inherent_data_providers = InherentDataProviders::new();
+        let env = proposer_factory(&client, &pool);
+
+        // queue a seal and close the stream before the authorship task gets to run.
+        let (tx, rx) = futures::channel::oneshot::channel();
+        sink.send(EngineCommand::SealNewBlock {
+            parent_hash: None,
+            sender: Some(tx),
+            create_empty: true,
+            finalize: false,
+        })
+        .await
+        .unwrap();
+        drop(sink);
+
+        run_manual_seal(ManualSealParams {
+            block_import: client.clone(),
+            env,
+            client: client.clone(),
+            pool: pool.pool().clone(),
+            commands_stream,
+            select_chain,
+            consensus_data_provider: None,
+            inherent_data_providers,
+            max_block_body_bytes: None,
+            justification_provider: None,
+            inherent_data_override: None,
+        })
+        .await;
+
+        // the queued seal still went through.
+        let created_block = rx.await.unwrap().unwrap();
+        let header = client.header(&BlockId::Number(1)).unwrap().unwrap();
+        assert_eq!(header.hash(), created_block.hash);
+    }
+
+    const INHERENTS_INTERMEDIATE: &[u8] = b"inherents";
+
+    /// Digest provider that supplies an empty digest and passes the inherent data the block was
+    /// built with on to the block import, the way babe hands its epoch data to `BabeBlockImport`.
+    struct ForwardingDataProvider;
+
+    impl ConsensusDataProvider<TestBlock> for ForwardingDataProvider {
+        type Transaction = TransactionFor<TestClient, TestBlock>;
+
+        fn create_digest(
+            &self,
+            _parent: &<TestBlock as BlockT>::Header,
+            _inherents: &InherentData,
+        ) -> Result<sp_runtime::traits::DigestFor<TestBlock>, Error> {
+            Ok(Default::default())
+        }
+
+        fn append_block_import(
+            &self,
+            _parent: &<TestBlock as BlockT>::Header,
+            params: &mut BlockImportParams<TestBlock, Self::Transaction>,
+            inherents: &InherentData,
+        ) -> Result<(), Error> {
+            params.intermediates.insert(
+                std::borrow::Cow::from(INHERENTS_INTERMEDIATE),
+                Box::new(inherents.clone()) as Box<_>,
+            );
+            Ok(())
+        }
+    }
+
+    /// Block import that rejects blocks built without the timestamp inherent, standing in for
+    /// the mandatory-inherent check the test runtime doesn't perform.
+    struct TimestampCheckingImport(Arc<TestClient>);
+
+    impl BlockImport<TestBlock> for TimestampCheckingImport {
+        type Error = sp_consensus::Error;
+        type Transaction = TransactionFor<TestClient, TestBlock>;
+
+        fn check_block(
+            &mut self,
+            block: sp_consensus::BlockCheckParams<TestBlock>,
+        ) -> Result<ImportResult, Self::Error> {
+            self.0.check_block(block)
+        }
+
+        fn import_block(
+            &mut self,
+            mut block: BlockImportParams<TestBlock, Self::Transaction>,
+            cache: std::collections::HashMap<CacheKeyId, Vec<u8>>,
+        ) -> Result<ImportResult, Self::Error> {
+            use sp_timestamp::TimestampInherentData;
+
+            let inherents = block.take_intermediate::<InherentData>(INHERENTS_INTERMEDIATE)?;
+            inherents
+                .timestamp_inherent_data()
+                .map_err(|e| sp_consensus::Error::ClientImport(e.into_string()))?;
+            self.0.import_block(block, cache)
+        }
+    }
+
+    #[tokio::test]
+    async fn sealing_without_inherents_fails() {
+        let (client, select_chain, pool, _sink, _) = setup_manual_seal();
+        let inherent_data_providers = InherentDataProviders::new();
+        inherent_data_providers
+            .register_provider(sp_timestamp::InherentDataProvider)
+            .unwrap();
+        let mut env = proposer_factory(&client, &pool);
+        let mut block_import = TimestampCheckingImport(client.clone());
+
+        let (tx, rx) = futures::channel::oneshot::channel();
+        seal_block(SealBlockParams {
+            sender: Some(tx),
+            parent_hash: None,
+            finalize: false,
+            create_empty: true,
+            env: &mut env,
+            select_chain: &select_chain,
+            block_import: &mut block_import,
+            inherent_data_provider: &inherent_data_providers,
+            skip_inherents: true,
+            inherent_data_override: None,
+            max_block_body_bytes: None,
+            consensus_data_provider: Some(&ForwardingDataProvider),
+            pool: pool.pool().clone(),
+            client: client.clone(),
+        })
+        .await;
+        // the block was proposed, but rejected on import.
+        assert_matches::assert_matches!(
+            rx.await.unwrap(),
+            Err(Error::ConsensusError(sp_consensus::Error::ClientImport(e)))
+                if e.contains("Timestamp inherent data not found")
+        );
+        assert!(client.header(&BlockId::Number(1)).unwrap().is_none());
+
+        // with the provider's inherents the same block imports fine.
+        let (tx, rx) = futures::channel::oneshot::channel();
+        seal_block(SealBlockParams {
+            sender: Some(tx),
+            parent_hash: None,
+            finalize: false,
+            create_empty: true,
+            env: &mut env,
+            select_chain: &select_chain,
+            block_import: &mut block_import,
+            inherent_data_provider: &inherent_data_providers,
+            skip_inherents: false,
+            inherent_data_override: None,
+            max_block_body_bytes: None,
+            consensus_data_provider: Some(&ForwardingDataProvider),
+            pool: pool.pool().clone(),
+            client: client.clone(),
+        })
+        .await;
+        assert_matches::assert_matches!(rx.await.unwrap(), Ok(_));
+        assert!(client.header(&BlockId::Number(1)).unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn seal_fails_when_block_body_exceeds_limit() {
+        let (client, select_chain, pool, mut sink, commands_stream) = setup_manual_seal();
+        let inherent_data_providers = InherentDataProviders::new();
+        let env = proposer_factory(&client, &pool);
+        let future = run_manual_seal(ManualSealParams {
+            block_import: client.clone(),
+            env,
+            client: client.clone(),
+            pool: pool.pool().clone(),
+            commands_stream,
+            select_chain,
+            consensus_data_provider: None,
+            inherent_data_providers,
+            max_block_body_bytes: Some(1),
+            justification_provider: None,
+            inherent_data_override: None,
+        });
+        std::thread::spawn(|| {
+            let mut rt = tokio::runtime::Runtime::new().unwrap();
+            // spawn the background authorship task
+            rt.block_on(future);
+        });
+        // submit a transaction to pool.
+        let result = pool
+            .submit_one(&BlockId::Number(0), SOURCE, uxt(Alice, 0))
+            .await;
+        assert!(result.is_ok());
+
+        let (tx, rx) = futures::channel::oneshot::channel();
+        sink.send(EngineCommand::SealNewBlock {
+            parent_hash: None,
+            sender: Some(tx),
+            create_empty: false,
+            finalize: false,
+        })
+        .await
+        .unwrap();
+
+        assert_matches::assert_matches!(
+            rx.await.unwrap(),
+            Err(Error::BlockTooLarge { limit: 1, .. })
+        );
+        // nothing was imported.
+        assert!(client.header(&BlockId::Number(1)).unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn clear_pool_drops_pending_transactions() {
+        let (client, select_chain, pool, mut sink, commands_stream) = setup_manual_seal();
+        let inherent_data_providers = InherentDataProviders::new();
+        let env = proposer_factory(&client, &pool);
+        let future = run_manual_seal(ManualSealParams {
+            block_import: client.clone(),
+            env,
+            client: client.clone(),
+            pool: pool.pool().clone(),
+            commands_stream,
+            select_chain,
+            consensus_data_provider: None,
+            inherent_data_providers,
+            max_block_body_bytes: None,
+            justification_provider: None,
+            inherent_data_override: None,
+        });
+        std::thread::spawn(|| {
+            let mut rt = tokio::runtime::Runtime::new().unwrap();
+            // spawn the background authorship task
+            rt.block_on(future);
+        });
+        // submit two transactions to the pool.
+        assert!(pool
+            .submit_one(&BlockId::Number(0), SOURCE, uxt(Alice, 0))
+            .await
+            .is_ok());
+        assert!(pool
+            .submit_one(&BlockId::Number(0), SOURCE, uxt(Bob, 0))
+            .await
+            .is_ok());
+        assert_eq!(pool.status().ready, 2);
+
+        let (tx, rx) = futures::channel::oneshot::channel();
+        sink.send(EngineCommand::ClearPool { sender: Some(tx) })
+            .await
+            .unwrap();
+        assert_eq!(rx.await.unwrap().unwrap(), 2);
+        assert_eq!(pool.status().ready, 0);
+
+        let (tx, rx) = futures::channel::oneshot::channel();
+        sink.send(EngineCommand::SealNewBlock {
+            parent_hash: None,
+            sender: Some(tx),
+            create_empty: true,
+            finalize: false,
+        })
+        .await
+        .unwrap();
+        let created_block = rx.await.unwrap().unwrap();
+
+        // neither transaction made it into the block.
+        let body = client
+            .block_body(&BlockId::Hash(created_block.hash))
+            .unwrap()
+            .unwrap();
+        assert!(body.is_empty());
+    }
 }
@@ -62,6 +62,16 @@ pub enum EngineCommand<Hash> {
         /// finalization justification
         justification: Option<Justification>,
     },
+    /// Asks the engine for the current best and finalized heads of the chain.
+    QueryHeads {
+        /// sender to report the heads to the rpc.
+        sender: Sender<ChainHeads<Hash>>,
+    },
+    /// Tells the engine to remove all ready and future transactions from the transaction pool.
+    ClearPool {
+        /// sender to report the number of removed transactions to the rpc.
+        sender: Sender<usize>,
+    },
 }
 
 /// RPC trait that provides methods for interacting with the manual-seal authorship task over rpc.
@@ -83,6 +93,14 @@ pub trait ManualSealApi<Hash> {
         hash: Hash,
         justification: Option<Justification>,
     ) -> FutureResult<bool>;
+
+    /// Queries the manual-seal authorship task for the current best and finalized heads
+    #[rpc(name = "engine_queryHeads")]
+    fn query_heads(&self) -> FutureResult<ChainHeads<Hash>>;
+
+    /// Instructs the manual-seal authorship task to remove all transactions from the pool
+    #[rpc(name = "engine_clearPool")]
+    fn clear_pool(&self) -> FutureResult<usize>;
 }
 
 /// A struct that implements the [`ManualSealApi`].
@@ -99,6 +117,19 @@ pub struct CreatedBlock<Hash> {
     pub aux: ImportedAux,
 }
 
+/// return type of `engine_queryHeads`
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct ChainHeads<Hash> {
+    /// hash of the best block.
+    pub best_hash: Hash,
+    /// number of the best block.
+    pub best_number: u64,
+    /// hash of the last finalized block.
+    pub finalized_hash: Hash,
+    /// number of the last finalized block.
+    pub finalized_number: u64,
+}
+
 impl<Hash> ManualSeal<Hash> {
     /// Create new `ManualSeal` with the given reference to the client.
     pub fn new(import_block_channel: mpsc::Sender<EngineCommand<Hash>>) -> Self {
@@ -152,6 +183,36 @@ impl<Hash: Send + 'static> ManualSealApi<Hash> for ManualSeal<Hash> {
 
         Box::new(future.boxed().map_err(Error::from).compat())
     }
+
+    fn query_heads(&self) -> FutureResult<ChainHeads<Hash>> {
+        let mut sink = self.import_block_channel.clone();
+        let future = async move {
+            let (sender, receiver) = oneshot::channel();
+            sink.send(EngineCommand::QueryHeads {
+                sender: Some(sender),
+            })
+            .await?;
+
+            receiver.await?
+        };
+
+        Box::new(future.boxed().map_err(Error::from).compat())
+    }
+
+    fn clear_pool(&self) -> FutureResult<usize> {
+        let mut sink = self.import_block_channel.clone();
+        let future = async move {
+            let (sender, receiver) = oneshot::channel();
+            sink.send(EngineCommand::ClearPool {
+                sender: Some(sender),
+            })
+            .await?;
+
+            receiver.await?
+        };
+
+        Box::new(future.boxed().map_err(Error::from).compat())
+    }
 }
 
 /// report any errors or successes encountered by the authorship task back
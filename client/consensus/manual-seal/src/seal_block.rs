@@ -19,6 +19,7 @@
 //! Block sealing utilities
 
 use crate::{rpc, ConsensusDataProvider, CreatedBlock, Error};
+use codec::Encode;
 use futures::prelude::*;
 use sc_transaction_pool::txpool;
 use sp_api::{ProvideRuntimeApi, TransactionFor};
@@ -27,7 +28,7 @@ use sp_consensus::{
     self, BlockImport, BlockImportParams, BlockOrigin, Environment, ForkChoiceStrategy,
     ImportResult, Proposer, SelectChain,
 };
-use sp_inherents::InherentDataProviders;
+use sp_inherents::{InherentData, InherentDataProviders};
 use sp_runtime::{
     generic::BlockId,
     traits::{Block as BlockT, Header as HeaderT},
@@ -65,6 +66,15 @@ pub struct SealBlockParams<'a, B: BlockT, BI, SC, C: ProvideRuntimeApi<B>, E, P:
     pub block_import: &'a mut BI,
     /// inherent data provider
     pub inherent_data_provider: &'a InherentDataProviders,
+    /// builds additional inherent data from the parent header, supplementing (and replacing
+    /// on identifier clashes) the data of `inherent_data_provider`.
+    pub inherent_data_override:
+        Option<&'a (dyn Fn(&<B as BlockT>::Header) -> InherentData + Send + Sync)>,
+    /// if true, the block is proposed with empty inherent data instead of the provider's,
+    /// e.g. to check that the runtime rejects blocks missing mandatory inherents.
+    pub skip_inherents: bool,
+    /// maximum size of the encoded block body, `None` disables the check.
+    pub max_block_body_bytes: Option<usize>,
 }
 
 /// seals a new block with the given params
@@ -79,7 +89,10 @@ pub async fn seal_block<B, BI, SC, C, E, P>(
         block_import,
         env,
         inherent_data_provider,
+        inherent_data_override,
         consensus_data_provider: digest_provider,
+        skip_inherents,
+        max_block_body_bytes,
         mut sender,
         ..
     }: SealBlockParams<'_, B, BI, SC, C, E, P>,
@@ -116,7 +129,14 @@ pub async fn seal_block<B, BI, SC, C, E, P>(
             .init(&parent)
             .map_err(|err| Error::StringError(format!("{:?}", err)))
             .await?;
-        let id = inherent_data_provider.create_inherent_data()?;
+        let mut id = if skip_inherents {
+            InherentData::new()
+        } else {
+            inherent_data_provider.create_inherent_data()?
+        };
+        if let Some(inherent_data_override) = inherent_data_override {
+            id.extend(inherent_data_override(&parent));
+        }
         let inherents_len = id.len();
 
         let digest = if let Some(digest_provider) = digest_provider {
@@ -139,6 +159,13 @@ pub async fn seal_block<B, BI, SC, C, E, P>(
             return Err(Error::EmptyTransactionPool);
         }
 
+        if let Some(limit) = max_block_body_bytes {
+            let size = proposal.block.extrinsics().encoded_size();
+            if size > limit {
+                return Err(Error::BlockTooLarge { size, limit });
+            }
+        }
+
         let (header, body) = proposal.block.deconstruct();
         let mut params = BlockImportParams::new(BlockOrigin::Own, header.clone());
         params.body = Some(body);
@@ -610,6 +610,14 @@ impl<FR> Store<FR> {
         }
     }
 
+    /// Returns the number of instances that have not been torn down yet.
+    pub fn instance_count(&self) -> u32 {
+        self.instances
+            .iter()
+            .filter(|instance| instance.is_some())
+            .count() as u32
+    }
+
     fn register_sandbox_instance(&mut self, sandbox_instance: Rc<SandboxInstance<FR>>) -> u32 {
         let instance_idx = self.instances.len();
         self.instances.push(Some(sandbox_instance));
@@ -38,6 +38,9 @@ extern "C" {
 
     #[allow(dead_code)]
     fn yet_another_missing_external();
+
+    #[allow(dead_code)]
+    fn ext_hashing_blake2_256_version_1(data: u64) -> u32;
 }
 
 #[cfg(not(feature = "std"))]
@@ -75,6 +78,11 @@ sp_core::wasm_export_functions! {
 
    fn test_empty_return() {}
 
+   fn test_host_read_out_of_bounds(address: u32, len: u32) {
+       // Hand the host a slice that lies outside of the linear memory.
+       unsafe { ext_hashing_blake2_256_version_1((u64::from(len) << 32) | u64::from(address)); }
+   }
+
    fn test_exhaust_heap() -> Vec<u8> { Vec::with_capacity(16777216) }
 
    fn test_panic() { panic!("test panic") }
@@ -174,11 +182,44 @@ sp_core::wasm_export_functions! {
            Err(sp_sandbox::Error::Module) => 1,
            Err(sp_sandbox::Error::Execution) => 2,
            Err(sp_sandbox::Error::OutOfBounds) => 3,
+           Err(sp_sandbox::Error::InstanceLimit) => 4,
        };
 
        code
    }
 
+   fn test_sandbox_instance_limit(code: Vec<u8>, count: u32) -> u32 {
+       let env_builder = sp_sandbox::EnvironmentDefinitionBuilder::new();
+       let mut instances = Vec::new();
+       for _ in 0..count {
+           match sp_sandbox::Instance::new(&code, &env_builder, &mut ()) {
+               Ok(instance) => instances.push(instance),
+               Err(sp_sandbox::Error::InstanceLimit) => break,
+               Err(_) => panic!("instantiation failed for a reason other than the instance limit"),
+           }
+       }
+
+       instances.len() as u32
+   }
+
+   fn test_sandbox_instance_teardown(code: Vec<u8>, count: u32) -> u32 {
+       let env_builder = sp_sandbox::EnvironmentDefinitionBuilder::new();
+       let first = sp_sandbox::Instance::new(&code, &env_builder, &mut ()).unwrap();
+       let _second = sp_sandbox::Instance::new(&code, &env_builder, &mut ()).unwrap();
+       drop(first);
+
+       // Count how many more instances fit next to the one still alive.
+       let mut instances = Vec::new();
+       for _ in 0..count {
+           match sp_sandbox::Instance::new(&code, &env_builder, &mut ()) {
+               Ok(instance) => instances.push(instance),
+               Err(_) => break,
+           }
+       }
+
+       instances.len() as u32
+   }
+
 
    fn test_sandbox_get_global_val(code: Vec<u8>) -> i64 {
        let env_builder = sp_sandbox::EnvironmentDefinitionBuilder::new();
@@ -21,6 +21,7 @@ use crate::test_wasm_execution;
 use crate::WasmExecutionMethod;
 
 use codec::Encode;
+use sp_core::sandbox::MAX_INSTANCES;
 
 test_wasm_execution!(sandbox_should_work);
 fn sandbox_should_work(wasm_method: WasmExecutionMethod) {
@@ -301,3 +302,83 @@ fn get_global_val_works(wasm_method: WasmExecutionMethod) {
         500i64.encode(),
     );
 }
+
+/// Calls `method` in the test runtime with an executor that keeps at most `limit` sandbox
+/// instances alive.
+test_wasm_execution!(instance_limit);
+fn instance_limit(wasm_method: WasmExecutionMethod) {
+    let mut ext = TestExternalities::default();
+    let mut ext = ext.ext();
+
+    let code = wat::parse_str(
+        r#"
+		(module
+			(func (export "call")
+			)
+		)
+		"#,
+    )
+    .unwrap();
+
+    // All instances up to the limit are created, the one past it is refused.
+    assert_eq!(
+        call_in_wasm(
+            "test_sandbox_instance_limit",
+            &(code, MAX_INSTANCES + 1).encode(),
+            wasm_method,
+            &mut ext,
+        )
+        .unwrap(),
+        MAX_INSTANCES.encode(),
+    );
+}
+
+test_wasm_execution!(instance_count_drops_on_teardown);
+fn instance_count_drops_on_teardown(wasm_method: WasmExecutionMethod) {
+    let mut ext = TestExternalities::default();
+    let mut ext = ext.ext();
+
+    let code = wat::parse_str(
+        r#"
+		(module
+			(func (export "call")
+			)
+		)
+		"#,
+    )
+    .unwrap();
+
+    // The instance limit is checked against `Sandbox::instance_count`: after two instances were
+    // created and one of them torn down, all but one of the instances fit under the limit.
+    assert_eq!(
+        call_in_wasm(
+            "test_sandbox_instance_teardown",
+            &(code, MAX_INSTANCES).encode(),
+            wasm_method,
+            &mut ext,
+        )
+        .unwrap(),
+        (MAX_INSTANCES - 1).encode(),
+    );
+}
+
+#[test]
+#[cfg(feature = "wasmtime")]
+fn host_read_out_of_bounds_reports_address_and_len() {
+    let mut ext = TestExternalities::default();
+    let mut ext = ext.ext();
+
+    let err = call_in_wasm(
+        "test_host_read_out_of_bounds",
+        &(0xfff0_0000u32, 16u32).encode(),
+        WasmExecutionMethod::Compiled,
+        &mut ext,
+    )
+    .unwrap_err();
+
+    assert!(
+        err.contains("read_memory_into failed at 0xfff00000 len 16"),
+        "unexpected error: {}",
+        err,
+    );
+}
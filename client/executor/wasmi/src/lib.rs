@@ -237,6 +237,10 @@ impl<'a> Sandbox for FunctionExecutor<'a> {
             .map_err(|e| e.to_string())
     }
 
+    fn instance_count(&self) -> u32 {
+        self.sandbox_store.instance_count()
+    }
+
     fn instance_new(
         &mut self,
         dispatch_thunk_id: u32,
@@ -244,6 +248,10 @@ impl<'a> Sandbox for FunctionExecutor<'a> {
         raw_env_def: &[u8],
         state: u32,
     ) -> WResult<u32> {
+        if self.sandbox_store.instance_count() >= sandbox_primitives::MAX_INSTANCES {
+            return Ok(sandbox_primitives::ERR_INSTANCE_LIMIT);
+        }
+
         // Extract a dispatch thunk from instance's table by the specified index.
         let dispatch_thunk = {
             let table = self
@@ -28,7 +28,7 @@ use sc_executor_common::sandbox::{self, SandboxCapabilities, SupervisorFuncIndex
 use sp_allocator::FreeingBumpHeapAllocator;
 use sp_core::sandbox as sandbox_primitives;
 use sp_wasm_interface::{FunctionContext, MemoryId, Pointer, Sandbox, WordSize};
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, fmt, rc::Rc};
 use wasmtime::{Func, Val};
 
 /// Wrapper type for pointer to a Wasm table entry.
@@ -84,6 +84,36 @@ impl<'a> std::ops::Deref for HostContext<'a> {
     }
 }
 
+/// Errors for an unexpected return value of a supervisor function invoked by the sandbox.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SandboxInvokeError {
+    /// The supervisor function returned `got` results instead of exactly one.
+    UnexpectedArity { got: usize },
+    /// The single result of the supervisor function is not an `i64`.
+    NonI64Result,
+}
+
+impl fmt::Display for SandboxInvokeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SandboxInvokeError::UnexpectedArity { got } => write!(
+                f,
+                "Supervisor function returned {} results, expected 1",
+                got
+            ),
+            SandboxInvokeError::NonI64Result => {
+                write!(f, "Supervisor function returned unexpected result!")
+            }
+        }
+    }
+}
+
+impl From<SandboxInvokeError> for sc_executor_common::error::Error {
+    fn from(err: SandboxInvokeError) -> Self {
+        err.to_string().into()
+    }
+}
+
 impl<'a> SandboxCapabilities for HostContext<'a> {
     type SupervisorFuncRef = SupervisorFuncRef;
 
@@ -104,10 +134,9 @@ impl<'a> SandboxCapabilities for HostContext<'a> {
         match result {
             Ok(ret_vals) => {
                 let ret_val = if ret_vals.len() != 1 {
-                    return Err(format!(
-                        "Supervisor function returned {} results, expected 1",
-                        ret_vals.len()
-                    )
+                    return Err(SandboxInvokeError::UnexpectedArity {
+                        got: ret_vals.len(),
+                    }
                     .into());
                 } else {
                     &ret_vals[0]
@@ -116,7 +145,7 @@ impl<'a> SandboxCapabilities for HostContext<'a> {
                 if let Some(ret_val) = ret_val.i64() {
                     Ok(ret_val)
                 } else {
-                    return Err("Supervisor function returned unexpected result!".into());
+                    return Err(SandboxInvokeError::NonI64Result.into());
                 }
             }
             Err(err) => Err(err.to_string().into()),
@@ -130,15 +159,25 @@ impl<'a> sp_wasm_interface::FunctionContext for HostContext<'a> {
         address: Pointer<u8>,
         dest: &mut [u8],
     ) -> sp_wasm_interface::Result<()> {
-        self.instance
-            .read_memory_into(address, dest)
-            .map_err(|e| e.to_string())
+        self.instance.read_memory_into(address, dest).map_err(|e| {
+            format!(
+                "read_memory_into failed at {:#x} len {}: {}",
+                u32::from(address),
+                dest.len(),
+                e,
+            )
+        })
     }
 
     fn write_memory(&mut self, address: Pointer<u8>, data: &[u8]) -> sp_wasm_interface::Result<()> {
-        self.instance
-            .write_memory_from(address, data)
-            .map_err(|e| e.to_string())
+        self.instance.write_memory_from(address, data).map_err(|e| {
+            format!(
+                "write_memory failed at {:#x} len {}: {}",
+                u32::from(address),
+                data.len(),
+                e,
+            )
+        })
     }
 
     fn allocate_memory(&mut self, size: WordSize) -> sp_wasm_interface::Result<Pointer<u8>> {
@@ -290,6 +329,10 @@ impl<'a> Sandbox for HostContext<'a> {
             .map_err(|e| e.to_string())
     }
 
+    fn instance_count(&self) -> u32 {
+        self.sandbox_store.borrow().instance_count()
+    }
+
     fn instance_new(
         &mut self,
         dispatch_thunk_id: u32,
@@ -297,6 +340,10 @@ impl<'a> Sandbox for HostContext<'a> {
         raw_env_def: &[u8],
         state: u32,
     ) -> sp_wasm_interface::Result<u32> {
+        if self.sandbox_store.borrow().instance_count() >= sandbox_primitives::MAX_INSTANCES {
+            return Ok(sandbox_primitives::ERR_INSTANCE_LIMIT);
+        }
+
         // Extract a dispatch thunk from the instance's table by the specified index.
         let dispatch_thunk = {
             let table_item = self
@@ -345,3 +392,20 @@ impl<'a> Sandbox for HostContext<'a> {
             .map_err(|e| e.to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sandbox_invoke_error_display_matches_messages() {
+        assert_eq!(
+            SandboxInvokeError::UnexpectedArity { got: 2 }.to_string(),
+            "Supervisor function returned 2 results, expected 1",
+        );
+        assert_eq!(
+            SandboxInvokeError::NonI64Result.to_string(),
+            "Supervisor function returned unexpected result!",
+        );
+    }
+}
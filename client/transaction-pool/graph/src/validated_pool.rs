@@ -635,6 +635,29 @@ impl<B: ChainApi> ValidatedPool<B> {
         invalid
     }
 
+    /// Remove all ready and future transactions from the pool.
+    ///
+    /// Unlike [`Self::remove_invalid`] the removed transactions are not banned, so they can be
+    /// submitted again right away.
+    pub fn clear(&self) -> Vec<TransactionFor<B>> {
+        let removed = {
+            let mut pool = self.pool.write();
+            let hashes = pool
+                .ready()
+                .map(|tx| tx.hash.clone())
+                .chain(pool.futures().map(|tx| tx.hash.clone()))
+                .collect::<Vec<_>>();
+            pool.remove_subtree(&hashes)
+        };
+
+        let mut listener = self.listener.write();
+        for tx in &removed {
+            listener.dropped(&tx.hash, None);
+        }
+
+        removed
+    }
+
     /// Get an iterator for ready transactions ordered by priority
     pub fn ready(&self) -> impl Iterator<Item = TransactionFor<B>> + Send {
         self.pool.read().ready()
@@ -48,6 +48,8 @@ parameter_types! {
     pub BlockWeights: frame_system::limits::BlockWeights =
         frame_system::limits::BlockWeights::simple_max(1024);
     pub const MinimumPeriod: u64 = 1;
+    pub const MedianWindow: u32 = 5;
+    pub const MaxPastDriftMillis: u64 = u64::max_value();
 }
 
 impl frame_system::Config for Test {
@@ -79,6 +81,8 @@ impl pallet_timestamp::Config for Test {
     type Moment = u64;
     type OnTimestampSet = Aura;
     type MinimumPeriod = MinimumPeriod;
+    type MedianWindow = MedianWindow;
+    type MaxPastDriftMillis = MaxPastDriftMillis;
     type WeightInfo = ();
 }
 
@@ -143,12 +143,16 @@ impl pallet_authorship::Config for Test {
 
 parameter_types! {
     pub const MinimumPeriod: u64 = 1;
+    pub const MedianWindow: u32 = 5;
+    pub const MaxPastDriftMillis: u64 = u64::max_value();
 }
 
 impl pallet_timestamp::Config for Test {
     type Moment = u64;
     type OnTimestampSet = Babe;
     type MinimumPeriod = MinimumPeriod;
+    type MedianWindow = MedianWindow;
+    type MaxPastDriftMillis = MaxPastDriftMillis;
     type WeightInfo = ();
 }
 
@@ -222,6 +226,7 @@ impl pallet_offences::Config for Test {
     type Event = Event;
     type IdentificationTuple = pallet_session::historical::IdentificationTuple<Self>;
     type OnOffenceHandler = Staking;
+    type OnReport = ();
     type WeightSoftLimit = OffencesWeightSoftLimit;
 }
 
@@ -315,6 +315,28 @@ where
         .into()
     }
 
+    /// Creates a wasm module that calls the imported function named `getter_name` `repeat`
+    /// times. The imported function is expected to take no arguments and to return its value
+    /// as an `i32`, which is dropped.
+    pub fn value_getter(getter_name: &'static str, repeat: u32) -> Self {
+        ModuleDefinition {
+            imported_functions: vec![ImportedFunction {
+                name: getter_name,
+                params: vec![],
+                return_type: Some(ValueType::I32),
+            }],
+            call_body: Some(body::repeated(
+                repeat,
+                &[
+                    Instruction::Call(0), // call the imported function
+                    Instruction::Drop,    // drop the returned value
+                ],
+            )),
+            ..Default::default()
+        }
+        .into()
+    }
+
     /// Creates a wasm module that calls the imported hash function named `name` `repeat` times
     /// with an input of size `data_size`. Hash functions have the signature
     /// (input_ptr: u32, input_len: u32, output_ptr: u32) -> ()
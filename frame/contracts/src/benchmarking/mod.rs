@@ -439,6 +439,22 @@ benchmarks! {
         let origin = RawOrigin::Signed(instance.caller.clone());
     }: call(origin, instance.addr, 0u32.into(), Weight::max_value(), vec![])
 
+    seal_caller_is_origin {
+        let r in 0 .. API_BENCHMARK_BATCHES;
+        let instance = Contract::<T>::new(WasmModule::value_getter(
+            "seal_caller_is_origin", r * API_BENCHMARK_BATCH_SIZE
+        ), vec![], Endow::Max)?;
+        let origin = RawOrigin::Signed(instance.caller.clone());
+    }: call(origin, instance.addr, 0u32.into(), Weight::max_value(), vec![])
+
+    seal_call_depth {
+        let r in 0 .. API_BENCHMARK_BATCHES;
+        let instance = Contract::<T>::new(WasmModule::value_getter(
+            "seal_call_depth", r * API_BENCHMARK_BATCH_SIZE
+        ), vec![], Endow::Max)?;
+        let origin = RawOrigin::Signed(instance.caller.clone());
+    }: call(origin, instance.addr, 0u32.into(), Weight::max_value(), vec![])
+
     seal_address {
         let r in 0 .. API_BENCHMARK_BATCHES;
         let instance = Contract::<T>::new(WasmModule::getter(
@@ -447,6 +463,14 @@ benchmarks! {
         let origin = RawOrigin::Signed(instance.caller.clone());
     }: call(origin, instance.addr, 0u32.into(), Weight::max_value(), vec![])
 
+    seal_own_salt {
+        let r in 0 .. API_BENCHMARK_BATCHES;
+        let instance = Contract::<T>::new(WasmModule::getter(
+            "seal_own_salt", r * API_BENCHMARK_BATCH_SIZE
+        ), vec![], Endow::Max)?;
+        let origin = RawOrigin::Signed(instance.caller.clone());
+    }: call(origin, instance.addr, 0u32.into(), Weight::max_value(), vec![])
+
     seal_gas_left {
         let r in 0 .. API_BENCHMARK_BATCHES;
         let instance = Contract::<T>::new(WasmModule::getter(
@@ -487,6 +511,14 @@ benchmarks! {
         let origin = RawOrigin::Signed(instance.caller.clone());
     }: call(origin, instance.addr, 0u32.into(), Weight::max_value(), vec![])
 
+    seal_code_deposit_per_byte {
+        let r in 0 .. API_BENCHMARK_BATCHES;
+        let instance = Contract::<T>::new(WasmModule::getter(
+            "seal_code_deposit_per_byte", r * API_BENCHMARK_BATCH_SIZE
+        ), vec![], Endow::Max)?;
+        let origin = RawOrigin::Signed(instance.caller.clone());
+    }: call(origin, instance.addr, 0u32.into(), Weight::max_value(), vec![])
+
     seal_rent_allowance {
         let r in 0 .. API_BENCHMARK_BATCHES;
         let instance = Contract::<T>::new(WasmModule::getter(
@@ -495,6 +527,14 @@ benchmarks! {
         let origin = RawOrigin::Signed(instance.caller.clone());
     }: call(origin, instance.addr, 0u32.into(), Weight::max_value(), vec![])
 
+    seal_rent_projection {
+        let r in 0 .. API_BENCHMARK_BATCHES;
+        let instance = Contract::<T>::new(WasmModule::getter(
+            "seal_rent_projection", r * API_BENCHMARK_BATCH_SIZE
+        ), vec![], Endow::Max)?;
+        let origin = RawOrigin::Signed(instance.caller.clone());
+    }: call(origin, instance.addr, 0u32.into(), Weight::max_value(), vec![])
+
     seal_block_number {
         let r in 0 .. API_BENCHMARK_BATCHES;
         let instance = Contract::<T>::new(WasmModule::getter(
@@ -503,6 +543,41 @@ benchmarks! {
         let origin = RawOrigin::Signed(instance.caller.clone());
     }: call(origin, instance.addr, 0u32.into(), Weight::max_value(), vec![])
 
+    seal_block_hash {
+        let r in 0 .. API_BENCHMARK_BATCHES;
+        let pages = code::max_pages::<T>();
+        let block_number = T::BlockNumber::zero().encode();
+        let block_number_len = block_number.len() as i32;
+        let code = WasmModule::<T>::from(ModuleDefinition {
+            memory: Some(ImportedMemory::max::<T>()),
+            imported_functions: vec![ImportedFunction {
+                name: "seal_block_hash",
+                params: vec![ValueType::I32, ValueType::I32, ValueType::I32, ValueType::I32],
+                return_type: None,
+            }],
+            data_segments: vec![
+                DataSegment {
+                    offset: 0,
+                    value: (pages * 64 * 1024 - 64).to_le_bytes().to_vec(),
+                },
+                DataSegment {
+                    offset: 4,
+                    value: block_number,
+                },
+            ],
+            call_body: Some(body::repeated(r * API_BENCHMARK_BATCH_SIZE, &[
+                Instruction::I32Const(4), // block_number_ptr
+                Instruction::I32Const(block_number_len), // block_number_len
+                Instruction::I32Const(64), // out_ptr
+                Instruction::I32Const(0), // out_len_ptr
+                Instruction::Call(0),
+            ])),
+            .. Default::default()
+        });
+        let instance = Contract::<T>::new(code, vec![], Endow::Max)?;
+        let origin = RawOrigin::Signed(instance.caller.clone());
+    }: call(origin, instance.addr, 0u32.into(), Weight::max_value(), vec![])
+
     seal_now {
         let r in 0 .. API_BENCHMARK_BATCHES;
         let instance = Contract::<T>::new(WasmModule::getter(
@@ -511,6 +586,14 @@ benchmarks! {
         let origin = RawOrigin::Signed(instance.caller.clone());
     }: call(origin, instance.addr, 0u32.into(), Weight::max_value(), vec![])
 
+    seal_schedule_version {
+        let r in 0 .. API_BENCHMARK_BATCHES;
+        let instance = Contract::<T>::new(WasmModule::value_getter(
+            "seal_schedule_version", r * API_BENCHMARK_BATCH_SIZE
+        ), vec![], Endow::Max)?;
+        let origin = RawOrigin::Signed(instance.caller.clone());
+    }: call(origin, instance.addr, 0u32.into(), Weight::max_value(), vec![])
+
     seal_weight_to_fee {
         let r in 0 .. API_BENCHMARK_BATCHES;
         let pages = code::max_pages::<T>();
@@ -615,6 +698,14 @@ benchmarks! {
         let origin = RawOrigin::Signed(instance.caller.clone());
     }: call(origin, instance.addr, 0u32.into(), Weight::max_value(), data)
 
+    seal_input_len {
+        let r in 0 .. API_BENCHMARK_BATCHES;
+        let instance = Contract::<T>::new(WasmModule::value_getter(
+            "seal_input_len", r * API_BENCHMARK_BATCH_SIZE
+        ), vec![], Endow::Max)?;
+        let origin = RawOrigin::Signed(instance.caller.clone());
+    }: call(origin, instance.addr, 0u32.into(), Weight::max_value(), vec![])
+
     // The same argument as for `seal_input` is true here.
     seal_return {
         let r in 0 .. 1;
@@ -697,6 +788,53 @@ benchmarks! {
         }
     }
 
+    // The same argument as for `seal_input` is true here.
+    seal_terminate_partial {
+        let r in 0 .. 1;
+        let beneficiary = account::<T::AccountId>("beneficiary", 0, 0);
+        let beneficiary_bytes = beneficiary.encode();
+        let beneficiary_len = beneficiary_bytes.len();
+        let value = Endow::max::<T>() / 2u32.into();
+        let value_bytes = value.encode();
+        let value_len = value_bytes.len();
+        let code = WasmModule::<T>::from(ModuleDefinition {
+            memory: Some(ImportedMemory::max::<T>()),
+            imported_functions: vec![ImportedFunction {
+                name: "seal_terminate_partial",
+                params: vec![ValueType::I32, ValueType::I32, ValueType::I32, ValueType::I32],
+                return_type: None,
+            }],
+            data_segments: vec![
+                DataSegment {
+                    offset: 0,
+                    value: beneficiary_bytes,
+                },
+                DataSegment {
+                    offset: beneficiary_len as u32,
+                    value: value_bytes,
+                },
+            ],
+            call_body: Some(body::repeated(r, &[
+                Instruction::I32Const(0), // beneficiary_ptr
+                Instruction::I32Const(beneficiary_len as i32), // beneficiary_len
+                Instruction::I32Const(beneficiary_len as i32), // value_ptr
+                Instruction::I32Const(value_len as i32), // value_len
+                Instruction::Call(0),
+            ])),
+            .. Default::default()
+        });
+        let instance = Contract::<T>::new(code, vec![], Endow::Max)?;
+        let origin = RawOrigin::Signed(instance.caller.clone());
+        assert_eq!(T::Currency::total_balance(&beneficiary), 0u32.into());
+        assert_eq!(T::Currency::total_balance(&instance.account_id), Endow::max::<T>());
+    }: call(origin, instance.addr, 0u32.into(), Weight::max_value(), vec![])
+    verify {
+        if r > 0 {
+            assert_eq!(T::Currency::total_balance(&instance.account_id), 0u32.into());
+            assert_eq!(T::Currency::total_balance(&beneficiary), value);
+        }
+    }
+
     seal_restore_to {
         let r in 0 .. 1;
 
@@ -951,6 +1089,14 @@ benchmarks! {
         let origin = RawOrigin::Signed(instance.caller.clone());
     }: call(origin, instance.addr, 0u32.into(), Weight::max_value(), vec![])
 
+    seal_events_emitted {
+        let r in 0 .. API_BENCHMARK_BATCHES;
+        let instance = Contract::<T>::new(WasmModule::value_getter(
+            "seal_events_emitted", r * API_BENCHMARK_BATCH_SIZE
+        ), vec![], Endow::Max)?;
+        let origin = RawOrigin::Signed(instance.caller.clone());
+    }: call(origin, instance.addr, 0u32.into(), Weight::max_value(), vec![])
+
     seal_set_rent_allowance {
         let r in 0 .. API_BENCHMARK_BATCHES;
         let allowance = caller_funding::<T>().encode();
@@ -2058,22 +2204,40 @@ benchmarks! {
     }
 
     // w_memory_grow = w_bench - 2 * w_param
+    // Growing by zero pages measures the overhead of the instruction itself. The pages
+    // requested are charged separately with `instr_memory_grow_per_page`.
+    instr_memory_grow {
+        let r in 0 .. INSTR_BENCHMARK_BATCHES;
+        let mut sbox = Sandbox::from(&WasmModule::<T>::from(ModuleDefinition {
+            memory: Some(ImportedMemory::max::<T>()),
+            call_body: Some(body::repeated(r * INSTR_BENCHMARK_BATCH_SIZE, &[
+                Instruction::I32Const(0),
+                Instruction::GrowMemory(0),
+                Instruction::Drop,
+            ])),
+            .. Default::default()
+        }));
+    }: {
+        sbox.invoke();
+    }
+
+    // w_memory_grow_per_page = w_bench
     // We can only allow allocate as much memory as it is allowed in a a contract.
-    // Therefore the repeat count is limited by the maximum memory any contract can have.
+    // Therefore the page count is limited by the maximum memory any contract can have.
     // Using a contract with more memory will skew the benchmark because the runtime of grow
     // depends on how much memory is already allocated.
-    instr_memory_grow {
-        let r in 0 .. 1;
-        let max_pages = ImportedMemory::max::<T>().max_pages;
+    instr_memory_grow_per_page {
+        let p in 0 .. ImportedMemory::max::<T>().max_pages;
         let mut sbox = Sandbox::from(&WasmModule::<T>::from(ModuleDefinition {
             memory: Some(ImportedMemory {
                 min_pages: 0,
-                max_pages,
+                max_pages: p,
             }),
-            call_body: Some(body::repeated(r * max_pages, &[
-                Instruction::I32Const(1),
+            call_body: Some(body::plain(vec![
+                Instruction::I32Const(p as i32),
                 Instruction::GrowMemory(0),
                 Instruction::Drop,
+                Instruction::End,
             ])),
             .. Default::default()
         }));
@@ -2474,27 +2638,37 @@ mod tests {
     create_test!(claim_surcharge);
 
     create_test!(seal_caller);
+    create_test!(seal_caller_is_origin);
+    create_test!(seal_call_depth);
     create_test!(seal_address);
+    create_test!(seal_own_salt);
     create_test!(seal_gas_left);
     create_test!(seal_balance);
     create_test!(seal_value_transferred);
     create_test!(seal_minimum_balance);
     create_test!(seal_tombstone_deposit);
+    create_test!(seal_code_deposit_per_byte);
     create_test!(seal_rent_allowance);
+    create_test!(seal_rent_projection);
     create_test!(seal_block_number);
+    create_test!(seal_block_hash);
     create_test!(seal_now);
+    create_test!(seal_schedule_version);
     create_test!(seal_weight_to_fee);
     create_test!(seal_gas);
     create_test!(seal_input);
     create_test!(seal_input_per_kb);
+    create_test!(seal_input_len);
     create_test!(seal_return);
     create_test!(seal_return_per_kb);
     create_test!(seal_terminate);
+    create_test!(seal_terminate_partial);
     create_test!(seal_restore_to);
     create_test!(seal_restore_to_per_delta);
     create_test!(seal_random);
     create_test!(seal_deposit_event);
     create_test!(seal_deposit_event_per_topic_and_kb);
+    create_test!(seal_events_emitted);
     create_test!(seal_set_rent_allowance);
     create_test!(seal_set_storage);
     create_test!(seal_set_storage_per_kb);
@@ -2534,6 +2708,7 @@ mod tests {
     create_test!(instr_global_set);
     create_test!(instr_memory_current);
     create_test!(instr_memory_grow);
+    create_test!(instr_memory_grow_per_page);
     create_test!(instr_i64clz);
     create_test!(instr_i64ctz);
     create_test!(instr_i64popcnt);
@@ -23,14 +23,14 @@ use crate::{
     RawEvent, Schedule, TrieId,
 };
 use frame_support::{
-    dispatch::{DispatchError, DispatchResult},
+    dispatch::{DispatchError, DispatchResult, DispatchResultWithPostInfo, Dispatchable},
     ensure,
     traits::{Currency, ExistenceRequirement, Get, Randomness, Time},
     weights::Weight,
     StorageMap,
 };
 use pallet_contracts_primitives::{
-    ErrorOrigin, ExecError, ExecResult, ExecReturnValue, ReturnFlags,
+    ErrorOrigin, ExecError, ExecResult, ExecReturnValue, RentProjection, ReturnFlags,
 };
 use sp_core::crypto::UncheckedFrom;
 use sp_runtime::traits::{Bounded, Convert, Saturating, Zero};
@@ -97,6 +97,16 @@ pub trait Ext {
     /// call stack.
     fn terminate(&mut self, beneficiary: &AccountIdOf<Self::T>) -> DispatchResult;
 
+    /// Transfer `value` to the `beneficiary` and the remaining balance to the origin of the
+    /// call stack, then remove the current contract.
+    ///
+    /// The same restrictions as for [`Self::terminate`] apply.
+    fn terminate_partial(
+        &mut self,
+        beneficiary: &AccountIdOf<Self::T>,
+        value: BalanceOf<Self::T>,
+    ) -> DispatchResult;
+
     /// Call (possibly transferring some amount of funds) into the specified account.
     fn call(
         &mut self,
@@ -124,9 +134,21 @@ pub trait Ext {
     /// Returns a reference to the account id of the caller.
     fn caller(&self) -> &AccountIdOf<Self::T>;
 
+    /// Returns whether the caller is the origin of the transaction, i.e. this contract was
+    /// called by a top-level call rather than by another contract.
+    fn caller_is_origin(&self) -> bool;
+
+    /// Returns the depth of the current contract in the call stack, `0` for a top-level call.
+    fn call_depth(&self) -> u32;
+
     /// Returns a reference to the account id of the current contract.
     fn address(&self) -> &AccountIdOf<Self::T>;
 
+    /// Returns the salt the current contract was instantiated with.
+    ///
+    /// The salt is only known while the constructor is running, it is empty otherwise.
+    fn own_salt(&self) -> &[u8];
+
     /// Returns the balance of the current contract.
     ///
     /// The `value_transferred` is already added.
@@ -144,6 +166,9 @@ pub trait Ext {
     /// Returns the deposit required to create a tombstone upon contract eviction.
     fn tombstone_deposit(&self) -> BalanceOf<Self::T>;
 
+    /// Returns the deposit a contract needs per byte of storage, including its code.
+    fn code_deposit_per_byte(&self) -> BalanceOf<Self::T>;
+
     /// Returns a random number for the current block with the given subject.
     fn random(&self, subject: &[u8]) -> SeedOf<Self::T>;
 
@@ -152,23 +177,44 @@ pub trait Ext {
     /// There should not be any duplicates in `topics`.
     fn deposit_event(&mut self, topics: Vec<TopicOf<Self::T>>, data: Vec<u8>);
 
+    /// Returns the number of events deposited by the current contract so far.
+    fn events_emitted(&self) -> u32;
+
     /// Set rent allowance of the contract
     fn set_rent_allowance(&mut self, rent_allowance: BalanceOf<Self::T>);
 
     /// Rent allowance of the contract
     fn rent_allowance(&self) -> BalanceOf<Self::T>;
 
+    /// Returns the block at which the contract is projected to be evicted for not paying rent.
+    fn rent_projection(&self) -> RentProjection<BlockNumberOf<Self::T>>;
+
     /// Returns the current block number.
     fn block_number(&self) -> BlockNumberOf<Self::T>;
 
+    /// Returns the hash of the block with the given number.
+    ///
+    /// Only the hashes of the recent blocks kept by `frame_system` are available, `None` is
+    /// returned for any other block number.
+    fn block_hash(
+        &self,
+        number: BlockNumberOf<Self::T>,
+    ) -> Option<<Self::T as frame_system::Config>::Hash>;
+
     /// Returns the maximum allowed size of a storage item.
     fn max_value_size(&self) -> u32;
 
+    /// Returns the maximum allowed size of the data attached to an event.
+    fn max_event_data_size(&self) -> u32;
+
     /// Returns the price for the specified amount of weight.
     fn get_weight_price(&self, weight: Weight) -> BalanceOf<Self::T>;
 
     /// Get a reference to the schedule used by the current call.
     fn schedule(&self) -> &Schedule<Self::T>;
+
+    /// Dispatch the given runtime call with the executing contract as signed origin.
+    fn call_runtime(&self, call: <Self::T as Config>::Call) -> DispatchResultWithPostInfo;
 }
 
 /// Describes the different functions that can be exported by an [`Executable`].
@@ -239,6 +285,7 @@ pub struct ExecutionContext<'a, T: Config + 'a, E> {
     pub caller: Option<&'a ExecutionContext<'a, T, E>>,
     pub self_account: T::AccountId,
     pub self_trie_id: Option<TrieId>,
+    pub self_salt: Vec<u8>,
     pub depth: usize,
     pub schedule: &'a Schedule<T>,
     pub timestamp: MomentOf<T>,
@@ -260,6 +307,7 @@ where
         ExecutionContext {
             caller: None,
             self_trie_id: None,
+            self_salt: Vec::new(),
             self_account: origin,
             depth: 0,
             schedule,
@@ -277,6 +325,7 @@ where
         ExecutionContext {
             caller: Some(self),
             self_trie_id: Some(trie_id),
+            self_salt: Vec::new(),
             self_account: dest,
             depth: self.depth + 1,
             schedule: self.schedule,
@@ -356,6 +405,8 @@ where
             let dest_trie_id = Storage::<T>::generate_trie_id(&dest);
 
             let output = self.with_nested_context(dest.clone(), dest_trie_id, |nested| {
+                nested.self_salt = salt.to_vec();
+
                 Storage::<T>::place_contract(
                     &dest,
                     nested
@@ -436,6 +487,7 @@ where
             value_transferred: value,
             timestamp,
             block_number,
+            events_emitted: 0,
             _phantom: Default::default(),
         }
     }
@@ -456,6 +508,15 @@ where
         })
     }
 
+    /// Returns the account that initiated the call stack, i.e. the self account of the
+    /// top-level context.
+    fn origin(&self) -> &T::AccountId {
+        match self.caller {
+            Some(caller) => caller.origin(),
+            None => &self.self_account,
+        }
+    }
+
     /// Returns whether a contract, identified by address, is currently live in the execution
     /// stack, meaning it is in the middle of an execution.
     fn is_live(&self, account: &T::AccountId) -> bool {
@@ -538,9 +599,34 @@ struct CallContext<'a, 'b: 'a, T: Config + 'b, E> {
     value_transferred: BalanceOf<T>,
     timestamp: MomentOf<T>,
     block_number: T::BlockNumber,
+    events_emitted: u32,
     _phantom: PhantomData<E>,
 }
 
+impl<'a, 'b: 'a, T, E> CallContext<'a, 'b, T, E>
+where
+    T: Config + 'b,
+    T::AccountId: UncheckedFrom<T::Hash> + AsRef<[u8]>,
+    E: Executable<T>,
+{
+    /// Remove the current contract after its balance was transferred away on termination.
+    fn remove_self(&mut self, beneficiary: &T::AccountId) -> DispatchResult {
+        let self_id = self.ctx.self_account.clone();
+        if let Some(ContractInfo::Alive(info)) = ContractInfoOf::<T>::take(&self_id) {
+            Storage::<T>::queue_trie_for_deletion(&info)?;
+            E::remove_user(info.code_hash);
+            Contracts::<T>::deposit_event(RawEvent::Terminated(self_id, beneficiary.clone()));
+            Ok(())
+        } else {
+            panic!(
+                "this function is only invoked by in the context of a contract;\
+				this contract is therefore alive;\
+				qed"
+            );
+        }
+    }
+}
+
 impl<'a, 'b: 'a, T, E> Ext for CallContext<'a, 'b, T, E>
 where
     T: Config + 'b,
@@ -614,18 +700,39 @@ where
             beneficiary,
             value,
         )?;
-        if let Some(ContractInfo::Alive(info)) = ContractInfoOf::<T>::take(&self_id) {
-            Storage::<T>::queue_trie_for_deletion(&info)?;
-            E::remove_user(info.code_hash);
-            Contracts::<T>::deposit_event(RawEvent::Terminated(self_id, beneficiary.clone()));
-            Ok(())
-        } else {
-            panic!(
-                "this function is only invoked by in the context of a contract;\
-				this contract is therefore alive;\
-				qed"
-            );
+        self.remove_self(beneficiary)
+    }
+
+    fn terminate_partial(
+        &mut self,
+        beneficiary: &AccountIdOf<Self::T>,
+        value: BalanceOf<Self::T>,
+    ) -> DispatchResult {
+        let self_id = self.ctx.self_account.clone();
+        if let Some(caller_ctx) = self.ctx.caller {
+            if caller_ctx.is_live(&self_id) {
+                return Err(Error::<T>::ReentranceDenied.into());
+            }
         }
+        transfer::<T>(
+            TransferCause::Terminate,
+            TransactorKind::Contract,
+            &self_id,
+            beneficiary,
+            value,
+        )?;
+        let rest = T::Currency::free_balance(&self_id);
+        if !rest.is_zero() {
+            let origin = self.ctx.origin().clone();
+            transfer::<T>(
+                TransferCause::Terminate,
+                TransactorKind::Contract,
+                &self_id,
+                &origin,
+                rest,
+            )?;
+        }
+        self.remove_self(beneficiary)
     }
 
     fn call(
@@ -676,10 +783,26 @@ where
         &self.ctx.self_account
     }
 
+    fn own_salt(&self) -> &[u8] {
+        &self.ctx.self_salt
+    }
+
     fn caller(&self) -> &T::AccountId {
         &self.caller
     }
 
+    fn caller_is_origin(&self) -> bool {
+        // The calling context is the top level one exactly when it has no caller itself.
+        self.ctx
+            .caller
+            .map_or(false, |caller| caller.caller.is_none())
+    }
+
+    fn call_depth(&self) -> u32 {
+        // Depth `0` belongs to the plain account that initiated the call stack.
+        self.ctx.depth.saturating_sub(1) as u32
+    }
+
     fn balance(&self) -> BalanceOf<T> {
         T::Currency::free_balance(&self.ctx.self_account)
     }
@@ -704,11 +827,20 @@ where
         T::TombstoneDeposit::get()
     }
 
+    fn code_deposit_per_byte(&self) -> BalanceOf<T> {
+        T::DepositPerStorageByte::get()
+    }
+
     fn deposit_event(&mut self, topics: Vec<T::Hash>, data: Vec<u8>) {
         deposit_event::<Self::T>(
             topics,
             RawEvent::ContractEmitted(self.ctx.self_account.clone(), data),
         );
+        self.events_emitted = self.events_emitted.saturating_add(1);
+    }
+
+    fn events_emitted(&self) -> u32 {
+        self.events_emitted
     }
 
     fn set_rent_allowance(&mut self, rent_allowance: BalanceOf<T>) {
@@ -727,14 +859,29 @@ where
             .unwrap_or_else(|_| <BalanceOf<T>>::max_value()) // Must never be triggered actually
     }
 
+    fn rent_projection(&self) -> RentProjection<T::BlockNumber> {
+        // The rent of the executing contract was already charged for this block when it was
+        // called, so the contract is alive and the projection only reads state.
+        Rent::<T, E>::compute_projection(&self.ctx.self_account)
+            .unwrap_or(RentProjection::NoEviction) // Must never be triggered actually
+    }
+
     fn block_number(&self) -> T::BlockNumber {
         self.block_number
     }
 
+    fn block_hash(&self, number: T::BlockNumber) -> Option<T::Hash> {
+        <frame_system::BlockHash<T>>::try_get(number).ok()
+    }
+
     fn max_value_size(&self) -> u32 {
         T::MaxValueSize::get()
     }
 
+    fn max_event_data_size(&self) -> u32 {
+        T::MaxValueSize::get()
+    }
+
     fn get_weight_price(&self, weight: Weight) -> BalanceOf<Self::T> {
         T::WeightPrice::convert(weight)
     }
@@ -742,6 +889,11 @@ where
     fn schedule(&self) -> &Schedule<Self::T> {
         &self.ctx.schedule
     }
+
+    fn call_runtime(&self, call: <Self::T as Config>::Call) -> DispatchResultWithPostInfo {
+        let origin = frame_system::RawOrigin::Signed(self.ctx.self_account.clone()).into();
+        call.dispatch(origin)
+    }
 }
 
 fn deposit_event<T: Config>(topics: Vec<T::Hash>, event: Event<T>) {
@@ -768,6 +920,7 @@ mod tests {
             ALICE, BOB, CHARLIE,
         },
         tests::{Event as MetaEvent, ExtBuilder, Test},
+        wasm::PrefabWasmModule,
         Error,
     };
     use assert_matches::assert_matches;
@@ -1432,6 +1585,40 @@ mod tests {
             });
     }
 
+    #[test]
+    fn terminate_partial_splits_balance() {
+        thread_local! {
+            static BALANCE_AT_TERMINATION: RefCell<u64> = RefCell::new(0);
+        }
+        let terminate_ch = MockLoader::insert(|ctx| {
+            BALANCE_AT_TERMINATION.with(|balance| *balance.borrow_mut() = ctx.ext.balance());
+            ctx.ext.terminate_partial(&CHARLIE, 30).unwrap();
+            exec_success()
+        });
+
+        ExtBuilder::default()
+            .existential_deposit(15)
+            .build()
+            .execute_with(|| {
+                let schedule = Contracts::current_schedule();
+                let mut ctx = MockContext::top_level(ALICE, &schedule);
+                set_balance(&ALICE, 1000);
+                place_contract(&BOB, terminate_ch);
+
+                assert_matches!(
+                    ctx.call(BOB, 0, &mut GasMeter::<Test>::new(GAS_LIMIT), vec![]),
+                    Ok(_)
+                );
+
+                // The beneficiary receives the value and the origin the rest.
+                assert_eq!(get_balance(&CHARLIE), 30);
+                let contract_balance = BALANCE_AT_TERMINATION.with(|balance| *balance.borrow());
+                assert_eq!(get_balance(&ALICE), 1000 + contract_balance - 30);
+                assert_eq!(get_balance(&BOB), 0);
+                assert!(ContractInfoOf::<Test>::get(&BOB).is_none());
+            });
+    }
+
     #[test]
     fn rent_allowance() {
         let rent_allowance_ch = MockLoader::insert(|ctx| {
@@ -1459,4 +1646,65 @@ mod tests {
             assert_matches!(result, Ok(_));
         });
     }
+
+    const CODE_SET_STORAGE: &str = r#"
+(module
+	(import "seal0" "seal_set_storage" (func $seal_set_storage (param i32 i32 i32)))
+	(import "env" "memory" (memory 1 1))
+
+	;; Storage key
+	(data (i32.const 0)
+		"\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01"
+		"\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01"
+	)
+
+	;; Value to store
+	(data (i32.const 32) "\2A")
+
+	(func (export "call")
+		(call $seal_set_storage
+			(i32.const 0)	;; Pointer to the storage key
+			(i32.const 32)	;; Pointer to the value
+			(i32.const 1)	;; Length of the value
+		)
+	)
+	(func (export "deploy"))
+)
+"#;
+
+    #[test]
+    fn dry_run_rolls_back_contract_storage() {
+        let wasm = wat::parse_str(CODE_SET_STORAGE).unwrap();
+
+        ExtBuilder::default().build().execute_with(|| {
+            let schedule = Contracts::current_schedule();
+            let executable = PrefabWasmModule::<Test>::from_code(wasm, &schedule).unwrap();
+            place_contract(&BOB, *executable.code_hash());
+            let trie_id = ContractInfoOf::<Test>::get(&BOB)
+                .and_then(|contract| contract.get_alive())
+                .unwrap()
+                .trie_id;
+            let ctx = ExecutionContext::<Test, PrefabWasmModule<Test>>::top_level(ALICE, &schedule);
+            let mut nested = ctx.nested(BOB, trie_id.clone());
+
+            let result = executable.clone().execute_dry_run(
+                nested.new_call_context(ALICE, 0),
+                &ExportedFunction::Call,
+                vec![],
+                &mut GasMeter::<Test>::new(GAS_LIMIT),
+            );
+            assert_matches!(result, Ok(_));
+            assert_eq!(Storage::<Test>::read(&trie_id, &[1; 32]), None);
+
+            // The same call without the dry run does write the value.
+            let result = executable.execute(
+                nested.new_call_context(ALICE, 0),
+                &ExportedFunction::Call,
+                vec![],
+                &mut GasMeter::<Test>::new(GAS_LIMIT),
+            );
+            assert_matches!(result, Ok(_));
+            assert_eq!(Storage::<Test>::read(&trie_id, &[1; 32]), Some(vec![42]));
+        });
+    }
 }
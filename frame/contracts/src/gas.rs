@@ -201,6 +201,13 @@ impl<T: Config> GasMeter<T> {
         self.gas_left
     }
 
+    /// Returns whether the gas left dropped below the given `threshold`.
+    ///
+    /// This allows to react before the meter actually runs out of gas.
+    pub fn gas_left_below(&self, threshold: Gas) -> bool {
+        self.gas_left < threshold
+    }
+
     /// Turn this GasMeter into a DispatchResult that contains the actually used gas.
     pub fn into_dispatch_result<R, E>(
         self,
@@ -355,6 +362,19 @@ mod tests {
         assert!(gas_meter.charge(&(), SimpleToken(1)).is_out_of_gas());
     }
 
+    #[test]
+    fn gas_left_below_flips_after_charging() {
+        let mut gas_meter = GasMeter::<Test>::new(100);
+        assert!(!gas_meter.gas_left_below(50));
+
+        assert!(!gas_meter.charge(&(), SimpleToken(50)).is_out_of_gas());
+        // Exactly at the threshold is not below it.
+        assert!(!gas_meter.gas_left_below(50));
+
+        assert!(!gas_meter.charge(&(), SimpleToken(1)).is_out_of_gas());
+        assert!(gas_meter.gas_left_below(50));
+    }
+
     // Charging the exact amount that the user paid for should be
     // possible.
     #[test]
@@ -80,7 +80,7 @@
 //! * [Balances](../pallet_balances/index.html)
 
 #![cfg_attr(not(feature = "std"), no_std)]
-#![cfg_attr(feature = "runtime-benchmarks", recursion_limit = "256")]
+#![cfg_attr(feature = "runtime-benchmarks", recursion_limit = "512")]
 
 #[macro_use]
 mod gas;
@@ -112,11 +112,12 @@ use codec::{Codec, Decode, Encode};
 use frame_support::weights::Weight;
 use frame_support::{
     decl_error, decl_event, decl_module, decl_storage,
-    dispatch::{DispatchResult, DispatchResultWithPostInfo},
+    dispatch::{DispatchResult, DispatchResultWithPostInfo, Dispatchable, PostDispatchInfo},
     ensure,
     storage::child::ChildInfo,
     traits::{Currency, Get, OnUnbalanced, Randomness, Time},
-    weights::Pays,
+    weights::{GetDispatchInfo, Pays},
+    Parameter,
 };
 use frame_system::{ensure_root, ensure_signed, Module as System};
 use pallet_contracts_primitives::{
@@ -284,6 +285,11 @@ pub trait Config: frame_system::Config {
     /// The overarching event type.
     type Event: From<Event<Self>> + Into<<Self as frame_system::Config>::Event>;
 
+    /// The overarching call type which contracts can dispatch through `seal_call_runtime`.
+    type Call: Parameter
+        + Dispatchable<Origin = Self::Origin, PostInfo = PostDispatchInfo>
+        + GetDispatchInfo;
+
     /// Handler for rent payments.
     type RentPayment: OnUnbalanced<NegativeImbalanceOf<Self>>;
 
@@ -442,6 +448,8 @@ decl_error! {
         StorageExhausted,
         /// A contract with the same AccountId already exists.
         DuplicateContract,
+        /// The data passed to `seal_deposit_event` exceeds the maximum event data size.
+        EventDataTooLarge,
     }
 }
 
@@ -28,7 +28,7 @@ use pwasm_utils::rules;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 use sp_runtime::RuntimeDebug;
-use sp_std::{marker::PhantomData, vec::Vec};
+use sp_std::{marker::PhantomData, num::NonZeroU32, vec::Vec};
 
 /// How many API calls are executed in a single batch. The reason for increasing the amount
 /// of API calls in batches (per benchmark component increase) is so that the linear regression
@@ -51,6 +51,12 @@ pub struct Schedule<T: Config> {
     /// MUST only be enabled for `dev` chains, NOT for production chains
     pub enable_println: bool,
 
+    /// Whether the `seal_call_runtime` function is allowed to be used by contracts.
+    pub enable_call_runtime: bool,
+
+    /// Whether events deposited by contracts may contain the same topic more than once.
+    pub allow_duplicate_topics: bool,
+
     /// Describes the upper limits on various metrics.
     pub limits: Limits,
 
@@ -159,6 +165,7 @@ pub struct InstructionWeights<T: Config> {
     pub global_set: u32,
     pub memory_current: u32,
     pub memory_grow: u32,
+    pub memory_grow_per_page: u32,
     pub i64clz: u32,
     pub i64ctz: u32,
     pub i64popcnt: u32,
@@ -202,9 +209,18 @@ pub struct HostFnWeights<T: Config> {
     /// Weight of calling `seal_caller`.
     pub caller: Weight,
 
+    /// Weight of calling `seal_caller_is_origin`.
+    pub caller_is_origin: Weight,
+
+    /// Weight of calling `seal_call_depth`.
+    pub call_depth: Weight,
+
     /// Weight of calling `seal_address`.
     pub address: Weight,
 
+    /// Weight of calling `seal_own_salt`.
+    pub own_salt: Weight,
+
     /// Weight of calling `seal_gas_left`.
     pub gas_left: Weight,
 
@@ -220,15 +236,27 @@ pub struct HostFnWeights<T: Config> {
     /// Weight of calling `seal_tombstone_deposit`.
     pub tombstone_deposit: Weight,
 
+    /// Weight of calling `seal_code_deposit_per_byte`.
+    pub code_deposit_per_byte: Weight,
+
     /// Weight of calling `seal_rent_allowance`.
     pub rent_allowance: Weight,
 
+    /// Weight of calling `seal_rent_projection`.
+    pub rent_projection: Weight,
+
     /// Weight of calling `seal_block_number`.
     pub block_number: Weight,
 
+    /// Weight of calling `seal_block_hash`.
+    pub block_hash: Weight,
+
     /// Weight of calling `seal_now`.
     pub now: Weight,
 
+    /// Weight of calling `seal_schedule_version`.
+    pub schedule_version: Weight,
+
     /// Weight of calling `seal_weight_to_fee`.
     pub weight_to_fee: Weight,
 
@@ -241,6 +269,9 @@ pub struct HostFnWeights<T: Config> {
     /// Weight per input byte copied to contract memory by `seal_input`.
     pub input_per_byte: Weight,
 
+    /// Weight of calling `seal_input_len`.
+    pub input_len: Weight,
+
     /// Weight of calling `seal_return`.
     pub r#return: Weight,
 
@@ -250,6 +281,9 @@ pub struct HostFnWeights<T: Config> {
     /// Weight of calling `seal_terminate`.
     pub terminate: Weight,
 
+    /// Weight of calling `seal_terminate_partial`.
+    pub terminate_partial: Weight,
+
     /// Weight of calling `seal_restore_to`.
     pub restore_to: Weight,
 
@@ -268,6 +302,9 @@ pub struct HostFnWeights<T: Config> {
     /// Weight per byte of an event deposited through `seal_deposit_event`.
     pub deposit_event_per_byte: Weight,
 
+    /// Weight of calling `seal_events_emitted`.
+    pub events_emitted: Weight,
+
     /// Weight of calling `seal_set_rent_allowance`.
     pub set_rent_allowance: Weight,
 
@@ -313,6 +350,9 @@ pub struct HostFnWeights<T: Config> {
     /// Weight per salt byte supplied to `seal_instantiate`.
     pub instantiate_per_salt_byte: Weight,
 
+    /// Weight of calling `seal_call_runtime` without the weight of the dispatched call.
+    pub call_runtime: Weight,
+
     /// Weight of calling `seal_hash_sha_256`.
     pub hash_sha2_256: Weight,
 
@@ -427,6 +467,8 @@ impl<T: Config> Default for Schedule<T> {
         Self {
             version: 0,
             enable_println: false,
+            enable_call_runtime: false,
+            allow_duplicate_topics: false,
             limits: Default::default(),
             instruction_weights: Default::default(),
             host_fn_weights: Default::default(),
@@ -454,7 +496,6 @@ impl Default for Limits {
 
 impl<T: Config> Default for InstructionWeights<T> {
     fn default() -> Self {
-        let max_pages = Limits::default().memory_pages;
         Self {
             i64const: cost_instr!(instr_i64const, 1),
             i64load: cost_instr!(instr_i64load, 2),
@@ -474,7 +515,8 @@ impl<T: Config> Default for InstructionWeights<T> {
             global_get: cost_instr!(instr_global_get, 1),
             global_set: cost_instr!(instr_global_set, 1),
             memory_current: cost_instr!(instr_memory_current, 1),
-            memory_grow: cost_instr_with_batch_size!(instr_memory_grow, 1, max_pages),
+            memory_grow: cost_instr!(instr_memory_grow, 1),
+            memory_grow_per_page: cost!(instr_memory_grow_per_page) as u32,
             i64clz: cost_instr!(instr_i64clz, 2),
             i64ctz: cost_instr!(instr_i64ctz, 2),
             i64popcnt: cost_instr!(instr_i64popcnt, 2),
@@ -516,22 +558,31 @@ impl<T: Config> Default for HostFnWeights<T> {
     fn default() -> Self {
         Self {
             caller: cost_batched!(seal_caller),
+            caller_is_origin: cost_batched!(seal_caller_is_origin),
+            call_depth: cost_batched!(seal_call_depth),
             address: cost_batched!(seal_address),
+            own_salt: cost_batched!(seal_own_salt),
             gas_left: cost_batched!(seal_gas_left),
             balance: cost_batched!(seal_balance),
             value_transferred: cost_batched!(seal_value_transferred),
             minimum_balance: cost_batched!(seal_minimum_balance),
             tombstone_deposit: cost_batched!(seal_tombstone_deposit),
+            code_deposit_per_byte: cost_batched!(seal_code_deposit_per_byte),
             rent_allowance: cost_batched!(seal_rent_allowance),
+            rent_projection: cost_batched!(seal_rent_projection),
             block_number: cost_batched!(seal_block_number),
+            block_hash: cost_batched!(seal_block_hash),
             now: cost_batched!(seal_now),
+            schedule_version: cost_batched!(seal_schedule_version),
             weight_to_fee: cost_batched!(seal_weight_to_fee),
             gas: cost_batched!(seal_gas),
             input: cost!(seal_input),
             input_per_byte: cost_byte!(seal_input_per_kb),
+            input_len: cost_batched!(seal_input_len),
             r#return: cost!(seal_return),
             return_per_byte: cost_byte!(seal_return_per_kb),
             terminate: cost!(seal_terminate),
+            terminate_partial: cost!(seal_terminate_partial),
             restore_to: cost!(seal_restore_to),
             restore_to_per_delta: cost_batched!(seal_restore_to_per_delta),
             random: cost_batched!(seal_random),
@@ -542,6 +593,7 @@ impl<T: Config> Default for HostFnWeights<T> {
                 0,
                 1
             ),
+            events_emitted: cost_batched!(seal_events_emitted),
             set_rent_allowance: cost_batched!(seal_set_rent_allowance),
             set_storage: cost_batched!(seal_set_storage),
             set_storage_per_byte: cost_byte_batched!(seal_set_storage_per_kb),
@@ -587,6 +639,8 @@ impl<T: Config> Default for HostFnWeights<T> {
                 0,
                 1
             ),
+            // Not benchmarked on its own: priced like `seal_call` as an upper bound.
+            call_runtime: cost_batched!(seal_call),
             hash_sha2_256: cost_batched!(seal_hash_sha2_256),
             hash_sha2_256_per_byte: cost_byte_batched!(seal_hash_sha2_256_per_kb),
             hash_keccak_256: cost_batched!(seal_hash_keccak_256),
@@ -707,9 +761,10 @@ impl<'a, T: Config> rules::Rules for ScheduleRules<'a, T> {
     }
 
     fn memory_grow_cost(&self) -> Option<rules::MemoryGrowCost> {
-        // We benchmarked the memory.grow instruction with the maximum allowed pages.
-        // The cost for growing is therefore already included in the instruction cost.
-        None
+        // The memory.grow instruction was benchmarked growing by zero pages. Every page
+        // requested is charged on top of that by the counter injected with the gas metering.
+        NonZeroU32::new(self.schedule.instruction_weights.memory_grow_per_page)
+            .map(rules::MemoryGrowCost::Linear)
     }
 }
 
@@ -242,11 +242,15 @@ impl pallet_balances::Config for Test {
 }
 parameter_types! {
     pub const MinimumPeriod: u64 = 1;
+    pub const MedianWindow: u32 = 5;
+    pub const MaxPastDriftMillis: u64 = u64::max_value();
 }
 impl pallet_timestamp::Config for Test {
     type Moment = u64;
     type OnTimestampSet = ();
     type MinimumPeriod = MinimumPeriod;
+    type MedianWindow = MedianWindow;
+    type MaxPastDriftMillis = MaxPastDriftMillis;
     type WeightInfo = ();
 }
 parameter_types! {
@@ -278,6 +282,7 @@ impl Config for Test {
     type Randomness = Randomness;
     type Currency = Balances;
     type Event = Event;
+    type Call = Call;
     type RentPayment = ();
     type SignedClaimHandicap = SignedClaimHandicap;
     type TombstoneDeposit = TombstoneDeposit;
@@ -567,7 +572,7 @@ fn deposit_event_max_value_limit() {
                     GAS_LIMIT,
                     (<Test as Config>::MaxValueSize::get() + 1).encode(),
                 ),
-                Error::<Test>::ValueTooLarge,
+                Error::<Test>::EventDataTooLarge,
             );
         });
 }
@@ -2902,3 +2907,43 @@ fn refcounter() {
             assert_matches!(crate::CodeStorage::<Test>::get(code_hash), None);
         });
 }
+
+#[test]
+fn will_be_removed_reports_last_reference() {
+    let (wasm, code_hash) = compile_module::<Test>("self_destruct").unwrap();
+    ExtBuilder::default()
+        .existential_deposit(50)
+        .build()
+        .execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, 1_000_000);
+            let subsistence = Module::<Test>::subsistence_threshold();
+
+            // Unknown code is never about to be removed.
+            assert!(!PrefabWasmModule::<Test>::will_be_removed(&code_hash));
+
+            for salt in 0..2u8 {
+                assert_ok!(Contracts::instantiate_with_code(
+                    Origin::signed(ALICE),
+                    subsistence * 100,
+                    GAS_LIMIT,
+                    wasm.clone(),
+                    vec![],
+                    vec![salt],
+                ));
+            }
+            assert_refcount!(code_hash, 2);
+            assert!(!PrefabWasmModule::<Test>::will_be_removed(&code_hash));
+
+            // Terminating one contract leaves a single reference.
+            let addr0 = Contracts::contract_address(&ALICE, &code_hash, &[0]);
+            assert_ok!(Contracts::call(
+                Origin::signed(ALICE),
+                addr0,
+                0,
+                GAS_LIMIT,
+                vec![],
+            ));
+            assert_refcount!(code_hash, 1);
+            assert!(PrefabWasmModule::<Test>::will_be_removed(&code_hash));
+        });
+}
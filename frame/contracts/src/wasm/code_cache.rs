@@ -108,6 +108,16 @@ where
     });
 }
 
+/// Returns whether the code stored under `code_hash` is removed by the next refcount decrement.
+///
+/// This is the case when exactly one contract still references the code.
+pub fn will_be_removed<T: Config>(code_hash: &CodeHash<T>) -> bool
+where
+    T::AccountId: UncheckedFrom<T::Hash> + AsRef<[u8]>,
+{
+    <CodeStorage<T>>::get(code_hash).map_or(false, |module| module.refcount == 1)
+}
+
 /// Load code with the given code hash.
 ///
 /// If the module was instrumented with a lower version of schedule than
@@ -129,6 +129,30 @@ where
         Ok(())
     }
 
+    /// Execute the specified exported function without committing any storage changes.
+    ///
+    /// The execution happens inside a storage transaction that is always rolled back, so
+    /// every change the contract makes through `ext` is discarded afterwards. Only the
+    /// returned value and the gas consumed by `gas_meter` remain.
+    pub fn execute_dry_run<E: Ext<T = T>>(
+        self,
+        ext: E,
+        function: &ExportedFunction,
+        input_data: Vec<u8>,
+        gas_meter: &mut GasMeter<T>,
+    ) -> ExecResult {
+        use frame_support::storage::{with_transaction, TransactionOutcome};
+        with_transaction(|| {
+            TransactionOutcome::Rollback(self.execute(ext, function, input_data, gas_meter))
+        })
+    }
+
+    /// Returns whether the code stored under `code_hash` is removed by the next refcount
+    /// decrement.
+    pub fn will_be_removed(code_hash: &CodeHash<T>) -> bool {
+        code_cache::will_be_removed::<T>(code_hash)
+    }
+
     /// Return the refcount of the module.
     #[cfg(test)]
     pub fn refcount(&self) -> u64 {
@@ -228,12 +252,17 @@ mod tests {
         BalanceOf, CodeHash, Error, Module as Contracts,
     };
     use assert_matches::assert_matches;
-    use frame_support::{dispatch::DispatchResult, weights::Weight};
+    use frame_support::{
+        dispatch::{DispatchResult, DispatchResultWithPostInfo},
+        weights::Weight,
+    };
     use hex_literal::hex;
-    use pallet_contracts_primitives::{ErrorOrigin, ExecError, ExecReturnValue, ReturnFlags};
+    use pallet_contracts_primitives::{
+        ErrorOrigin, ExecError, ExecReturnValue, RentProjection, ReturnFlags,
+    };
     use sp_core::H256;
     use sp_runtime::DispatchError;
-    use std::collections::HashMap;
+    use std::{cell::RefCell, collections::HashMap};
 
     const GAS_LIMIT: Gas = 10_000_000_000;
 
@@ -262,6 +291,12 @@ mod tests {
         beneficiary: AccountIdOf<Test>,
     }
 
+    #[derive(Debug, PartialEq, Eq)]
+    struct PartialTerminationEntry {
+        beneficiary: AccountIdOf<Test>,
+        value: u64,
+    }
+
     #[derive(Debug, PartialEq, Eq)]
     struct TransferEntry {
         to: AccountIdOf<Test>,
@@ -275,11 +310,21 @@ mod tests {
         rent_allowance: u64,
         instantiates: Vec<InstantiateEntry>,
         terminations: Vec<TerminationEntry>,
+        partial_terminations: Vec<PartialTerminationEntry>,
         transfers: Vec<TransferEntry>,
         restores: Vec<RestoreEntry>,
         // (topics, data)
         events: Vec<(Vec<H256>, Vec<u8>)>,
+        runtime_calls: RefCell<Vec<DispatchEntry>>,
         schedule: Schedule<Test>,
+        caller_is_origin: bool,
+        call_depth: u32,
+        block_hashes: HashMap<u64, H256>,
+        salt: Vec<u8>,
+        // `None` projects no eviction.
+        eviction_at: Option<u64>,
+        // `None` falls back to `max_value_size`.
+        max_event_data_size: Option<u32>,
     }
 
     impl Ext for MockExt {
@@ -289,7 +334,10 @@ mod tests {
             self.storage.get(key).cloned()
         }
         fn set_storage(&mut self, key: StorageKey, value: Option<Vec<u8>>) -> DispatchResult {
-            *self.storage.entry(key).or_insert(Vec::new()) = value.unwrap_or(Vec::new());
+            match value {
+                Some(value) => self.storage.insert(key, value),
+                None => self.storage.remove(&key),
+            };
             Ok(())
         }
         fn instantiate(
@@ -348,6 +396,17 @@ mod tests {
             });
             Ok(())
         }
+        fn terminate_partial(
+            &mut self,
+            beneficiary: &AccountIdOf<Self::T>,
+            value: u64,
+        ) -> Result<(), DispatchError> {
+            self.partial_terminations.push(PartialTerminationEntry {
+                beneficiary: beneficiary.clone(),
+                value,
+            });
+            Ok(())
+        }
         fn restore_to(
             &mut self,
             dest: AccountIdOf<Self::T>,
@@ -366,9 +425,18 @@ mod tests {
         fn caller(&self) -> &AccountIdOf<Self::T> {
             &ALICE
         }
+        fn caller_is_origin(&self) -> bool {
+            self.caller_is_origin
+        }
+        fn call_depth(&self) -> u32 {
+            self.call_depth
+        }
         fn address(&self) -> &AccountIdOf<Self::T> {
             &BOB
         }
+        fn own_salt(&self) -> &[u8] {
+            &self.salt
+        }
         fn balance(&self) -> u64 {
             228
         }
@@ -387,6 +455,9 @@ mod tests {
         fn tombstone_deposit(&self) -> u64 {
             16
         }
+        fn code_deposit_per_byte(&self) -> u64 {
+            100
+        }
 
         fn random(&self, subject: &[u8]) -> H256 {
             H256::from_slice(subject)
@@ -396,6 +467,10 @@ mod tests {
             self.events.push((topics, data))
         }
 
+        fn events_emitted(&self) -> u32 {
+            self.events.len() as u32
+        }
+
         fn set_rent_allowance(&mut self, rent_allowance: u64) {
             self.rent_allowance = rent_allowance;
         }
@@ -404,13 +479,25 @@ mod tests {
             self.rent_allowance
         }
 
+        fn rent_projection(&self) -> RentProjection<u64> {
+            self.eviction_at
+                .map_or(RentProjection::NoEviction, RentProjection::EvictionAt)
+        }
+
         fn block_number(&self) -> u64 {
             121
         }
+        fn block_hash(&self, number: u64) -> Option<H256> {
+            self.block_hashes.get(&number).cloned()
+        }
 
         fn max_value_size(&self) -> u32 {
             16_384
         }
+        fn max_event_data_size(&self) -> u32 {
+            self.max_event_data_size
+                .unwrap_or_else(|| self.max_value_size())
+        }
 
         fn get_weight_price(&self, weight: Weight) -> BalanceOf<Self::T> {
             BalanceOf::<Self::T>::from(1312_u32).saturating_mul(weight.into())
@@ -419,6 +506,11 @@ mod tests {
         fn schedule(&self) -> &Schedule<Self::T> {
             &self.schedule
         }
+
+        fn call_runtime(&self, call: Call) -> DispatchResultWithPostInfo {
+            self.runtime_calls.borrow_mut().push(DispatchEntry(call));
+            Ok(Default::default())
+        }
     }
 
     impl Ext for &mut MockExt {
@@ -446,6 +538,13 @@ mod tests {
         fn terminate(&mut self, beneficiary: &AccountIdOf<Self::T>) -> Result<(), DispatchError> {
             (**self).terminate(beneficiary)
         }
+        fn terminate_partial(
+            &mut self,
+            beneficiary: &AccountIdOf<Self::T>,
+            value: u64,
+        ) -> Result<(), DispatchError> {
+            (**self).terminate_partial(beneficiary, value)
+        }
         fn call(
             &mut self,
             to: &AccountIdOf<Self::T>,
@@ -467,9 +566,18 @@ mod tests {
         fn caller(&self) -> &AccountIdOf<Self::T> {
             (**self).caller()
         }
+        fn caller_is_origin(&self) -> bool {
+            (**self).caller_is_origin()
+        }
+        fn call_depth(&self) -> u32 {
+            (**self).call_depth()
+        }
         fn address(&self) -> &AccountIdOf<Self::T> {
             (**self).address()
         }
+        fn own_salt(&self) -> &[u8] {
+            (**self).own_salt()
+        }
         fn balance(&self) -> u64 {
             (**self).balance()
         }
@@ -485,30 +593,48 @@ mod tests {
         fn tombstone_deposit(&self) -> u64 {
             (**self).tombstone_deposit()
         }
+        fn code_deposit_per_byte(&self) -> u64 {
+            (**self).code_deposit_per_byte()
+        }
         fn random(&self, subject: &[u8]) -> H256 {
             (**self).random(subject)
         }
         fn deposit_event(&mut self, topics: Vec<H256>, data: Vec<u8>) {
             (**self).deposit_event(topics, data)
         }
+        fn events_emitted(&self) -> u32 {
+            (**self).events_emitted()
+        }
         fn set_rent_allowance(&mut self, rent_allowance: u64) {
             (**self).set_rent_allowance(rent_allowance)
         }
         fn rent_allowance(&self) -> u64 {
             (**self).rent_allowance()
         }
+        fn rent_projection(&self) -> RentProjection<u64> {
+            (**self).rent_projection()
+        }
         fn block_number(&self) -> u64 {
             (**self).block_number()
         }
+        fn block_hash(&self, number: u64) -> Option<H256> {
+            (**self).block_hash(number)
+        }
         fn max_value_size(&self) -> u32 {
             (**self).max_value_size()
         }
+        fn max_event_data_size(&self) -> u32 {
+            (**self).max_event_data_size()
+        }
         fn get_weight_price(&self, weight: Weight) -> BalanceOf<Self::T> {
             (**self).get_weight_price(weight)
         }
         fn schedule(&self) -> &Schedule<Self::T> {
             (**self).schedule()
         }
+        fn call_runtime(&self, call: Call) -> DispatchResultWithPostInfo {
+            (**self).call_runtime(call)
+        }
     }
 
     fn execute<E: Ext>(
@@ -522,7 +648,7 @@ mod tests {
             UncheckedFrom<<E::T as frame_system::Config>::Hash> + AsRef<[u8]>,
     {
         let wasm = wat::parse_str(wat).unwrap();
-        let schedule = crate::Schedule::default();
+        let schedule = ext.schedule().clone();
         let executable = PrefabWasmModule::<E::T>::from_code(wasm, &schedule).unwrap();
         executable.execute(ext, &ExportedFunction::Call, input_data, gas_meter)
     }
@@ -582,6 +708,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn contract_transfer_malformed_value_len() {
+        // the value is a u64, so a 4 byte buffer can't be decoded.
+        let code = CODE_TRANSFER.replace(
+            "(i32.const 8)  ;; Length of the buffer with value to transfer.",
+            "(i32.const 4)  ;; Length of the buffer with value to transfer.",
+        );
+        let mut mock_ext = MockExt::default();
+        let result = execute(&code, vec![], &mut mock_ext, &mut GasMeter::new(GAS_LIMIT));
+
+        assert_eq!(
+            result,
+            Err(ExecError {
+                error: Error::<Test>::DecodingFailed.into(),
+                origin: ErrorOrigin::Caller,
+            })
+        );
+        assert!(mock_ext.transfers.is_empty());
+    }
+
     const CODE_CALL: &str = r#"
 (module
 	;; seal_call(
@@ -772,6 +918,59 @@ mod tests {
         );
     }
 
+    const CODE_TERMINATE_PARTIAL: &str = r#"
+(module
+	;; seal_terminate_partial(
+	;;     beneficiary_ptr: u32,
+	;;     beneficiary_len: u32,
+	;;     value_ptr: u32,
+	;;     value_len: u32,
+	;; )
+	(import "seal0" "seal_terminate_partial" (func $seal_terminate_partial (param i32 i32 i32 i32)))
+	(import "env" "memory" (memory 1 1))
+	(func (export "call")
+		(call $seal_terminate_partial
+			(i32.const 4)  ;; Pointer to "beneficiary" address.
+			(i32.const 32)  ;; Length of "beneficiary" address.
+			(i32.const 36)  ;; Pointer to the buffer with value to transfer.
+			(i32.const 8)  ;; Length of the buffer with value to transfer.
+		)
+	)
+	(func (export "deploy"))
+
+	;; Beneficiary AccountId to transfer the value to.
+	(data (i32.const 4)
+		"\02\02\02\02\02\02\02\02\02\02\02\02\02\02\02\02"
+		"\02\02\02\02\02\02\02\02\02\02\02\02\02\02\02\02"
+	)
+
+	;; Amount of value to transfer.
+	;; Represented by u64 (8 bytes long) in little endian.
+	(data (i32.const 36) "\99\00\00\00\00\00\00\00")
+)
+"#;
+
+    #[test]
+    fn contract_terminate_partial() {
+        let mut mock_ext = MockExt::default();
+        execute(
+            CODE_TERMINATE_PARTIAL,
+            vec![],
+            &mut mock_ext,
+            &mut GasMeter::new(GAS_LIMIT),
+        )
+        .unwrap();
+
+        assert_eq!(
+            &mock_ext.partial_terminations,
+            &[PartialTerminationEntry {
+                beneficiary: BOB,
+                value: 153,
+            }]
+        );
+        assert!(mock_ext.terminations.is_empty());
+    }
+
     const CODE_TRANSFER_LIMITED_GAS: &str = r#"
 (module
 	;; seal_call(
@@ -924,6 +1123,90 @@ mod tests {
         );
     }
 
+    const CODE_SET_STORAGE_BATCH: &str = r#"
+(module
+	(import "seal0" "seal_set_storage_batch" (func $seal_set_storage_batch (param i32 i32)))
+	(import "seal0" "seal_get_storage" (func $seal_get_storage (param i32 i32 i32) (result i32)))
+	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+	(import "env" "memory" (memory 1 1))
+
+	;; [0, 106) SCALE encoded batch of three writes: [1; 32] => 0x11, [2; 32] => 0x22
+	;; and [3; 32] => 0x33. The keys start at the offsets 1, 36 and 71.
+	(data (i32.const 0)
+		"\0c"
+		"\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01"
+		"\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01"
+		"\01\04\11"
+		"\02\02\02\02\02\02\02\02\02\02\02\02\02\02\02\02"
+		"\02\02\02\02\02\02\02\02\02\02\02\02\02\02\02\02"
+		"\01\04\22"
+		"\03\03\03\03\03\03\03\03\03\03\03\03\03\03\03\03"
+		"\03\03\03\03\03\03\03\03\03\03\03\03\03\03\03\03"
+		"\01\04\33"
+	)
+
+	;; [128, 140) buffer sizes for reading back each value = 1 byte
+	(data (i32.const 128) "\01\00\00\00\01\00\00\00\01\00\00\00")
+
+	;; [160, 163) buffer where the values are read back to
+
+	(func $assert (param i32)
+		(block $ok
+			(br_if $ok
+				(get_local 0)
+			)
+			(unreachable)
+		)
+	)
+
+	(func (export "call")
+		(call $seal_set_storage_batch
+			(i32.const 0)	;; Pointer to the encoded batch
+			(i32.const 106)	;; Length of the encoded batch
+		)
+
+		;; Read back every value on its own.
+		(call $assert
+			(i32.eqz (call $seal_get_storage (i32.const 1) (i32.const 160) (i32.const 128)))
+		)
+		(call $assert
+			(i32.eqz (call $seal_get_storage (i32.const 36) (i32.const 161) (i32.const 132)))
+		)
+		(call $assert
+			(i32.eqz (call $seal_get_storage (i32.const 71) (i32.const 162) (i32.const 136)))
+		)
+
+		(call $seal_return
+			(i32.const 0)
+			(i32.const 160)
+			(i32.const 3)
+		)
+		(unreachable)
+	)
+
+	(func (export "deploy"))
+)
+"#;
+
+    #[test]
+    fn set_storage_batch_works() {
+        let output = execute(
+            CODE_SET_STORAGE_BATCH,
+            vec![],
+            MockExt::default(),
+            &mut GasMeter::new(GAS_LIMIT),
+        )
+        .unwrap();
+
+        assert_eq!(
+            output,
+            ExecReturnValue {
+                flags: ReturnFlags::empty(),
+                data: vec![0x11, 0x22, 0x33],
+            }
+        );
+    }
+
     /// calls `seal_caller` and compares the result with the constant 42.
     const CODE_CALLER: &str = r#"
 (module
@@ -978,6 +1261,107 @@ mod tests {
         .unwrap();
     }
 
+    const CODE_CALLER_IS_ORIGIN: &str = r#"
+(module
+	(import "seal0" "seal_caller_is_origin" (func $seal_caller_is_origin (result i32)))
+	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+	(import "env" "memory" (memory 1 1))
+
+	(func (export "call")
+		;; Return the flag as LE encoded u32.
+		(i32.store (i32.const 0) (call $seal_caller_is_origin))
+		(call $seal_return (i32.const 0) (i32.const 0) (i32.const 4))
+		(unreachable)
+	)
+
+	(func (export "deploy"))
+)
+"#;
+
+    #[test]
+    fn caller_is_origin() {
+        for &is_origin in &[true, false] {
+            let mut mock_ext = MockExt::default();
+            mock_ext.caller_is_origin = is_origin;
+
+            let output = execute(
+                CODE_CALLER_IS_ORIGIN,
+                vec![],
+                mock_ext,
+                &mut GasMeter::new(GAS_LIMIT),
+            )
+            .unwrap();
+
+            assert_eq!(output.data, (is_origin as u32).encode());
+        }
+    }
+
+    const CODE_CALL_DEPTH: &str = r#"
+(module
+	(import "seal0" "seal_call_depth" (func $seal_call_depth (result i32)))
+	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+	(import "env" "memory" (memory 1 1))
+
+	(func (export "call")
+		;; Return the depth as LE encoded u32.
+		(i32.store (i32.const 0) (call $seal_call_depth))
+		(call $seal_return (i32.const 0) (i32.const 0) (i32.const 4))
+		(unreachable)
+	)
+
+	(func (export "deploy"))
+)
+"#;
+
+    #[test]
+    fn call_depth() {
+        let mut mock_ext = MockExt::default();
+        mock_ext.call_depth = 3;
+
+        let output = execute(
+            CODE_CALL_DEPTH,
+            vec![],
+            mock_ext,
+            &mut GasMeter::new(GAS_LIMIT),
+        )
+        .unwrap();
+
+        assert_eq!(output.data, 3u32.encode());
+    }
+
+    const CODE_SCHEDULE_VERSION: &str = r#"
+(module
+	(import "seal0" "seal_schedule_version" (func $seal_schedule_version (result i32)))
+	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+	(import "env" "memory" (memory 1 1))
+
+	(func (export "call")
+		;; Return the version as LE encoded u32.
+		(i32.store (i32.const 0) (call $seal_schedule_version))
+		(call $seal_return (i32.const 0) (i32.const 0) (i32.const 4))
+		(unreachable)
+	)
+
+	(func (export "deploy"))
+)
+"#;
+
+    #[test]
+    fn schedule_version() {
+        let output = execute(
+            CODE_SCHEDULE_VERSION,
+            vec![],
+            MockExt::default(),
+            &mut GasMeter::new(GAS_LIMIT),
+        )
+        .unwrap();
+
+        assert_eq!(
+            output.data,
+            crate::Schedule::<Test>::default().version.encode()
+        );
+    }
+
     /// calls `seal_address` and compares the result with the constant 69.
     const CODE_ADDRESS: &str = r#"
 (module
@@ -1432,22 +1816,13 @@ mod tests {
         .unwrap();
     }
 
-    const CODE_RANDOM: &str = r#"
+    const CODE_CODE_DEPOSIT_PER_BYTE: &str = r#"
 (module
-	(import "seal0" "seal_random" (func $seal_random (param i32 i32 i32 i32)))
-	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+	(import "seal0" "seal_code_deposit_per_byte" (func $seal_code_deposit_per_byte (param i32 i32)))
 	(import "env" "memory" (memory 1 1))
 
-	;; [0,128) is reserved for the result of PRNG.
-
-	;; the subject used for the PRNG. [128,160)
-	(data (i32.const 128)
-		"\00\01\02\03\04\05\06\07\08\09\0A\0B\0C\0D\0E\0F"
-		"\00\01\02\03\04\05\06\07\08\09\0A\0B\0C\0D\0E\0F"
-	)
-
-	;; size of our buffer is 128 bytes
-	(data (i32.const 160) "\80")
+	;; size of our buffer is 32 bytes
+	(data (i32.const 32) "\20")
 
 	(func $assert (param i32)
 		(block $ok
@@ -1459,24 +1834,85 @@ mod tests {
 	)
 
 	(func (export "call")
-		;; This stores the block random seed in the buffer
-		(call $seal_random
-			(i32.const 128) ;; Pointer in memory to the start of the subject buffer
-			(i32.const 32) ;; The subject buffer's length
-			(i32.const 0) ;; Pointer to the output buffer
-			(i32.const 160) ;; Pointer to the output buffer length
-		)
+		(call $seal_code_deposit_per_byte (i32.const 0) (i32.const 32))
 
-		;; assert len == 32
+		;; assert len == 8
 		(call $assert
 			(i32.eq
-				(i32.load (i32.const 160))
-				(i32.const 32)
+				(i32.load (i32.const 32))
+				(i32.const 8)
 			)
 		)
 
-		;; return the random data
-		(call $seal_return
+		;; assert that contents of the buffer is equal to the i64 value of 100.
+		(call $assert
+			(i64.eq
+				(i64.load (i32.const 0))
+				(i64.const 100)
+			)
+		)
+	)
+	(func (export "deploy"))
+)
+"#;
+
+    #[test]
+    fn code_deposit_per_byte() {
+        let mut gas_meter = GasMeter::new(GAS_LIMIT);
+        let _ = execute(
+            CODE_CODE_DEPOSIT_PER_BYTE,
+            vec![],
+            MockExt::default(),
+            &mut gas_meter,
+        )
+        .unwrap();
+    }
+
+    const CODE_RANDOM: &str = r#"
+(module
+	(import "seal0" "seal_random" (func $seal_random (param i32 i32 i32 i32)))
+	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+	(import "env" "memory" (memory 1 1))
+
+	;; [0,128) is reserved for the result of PRNG.
+
+	;; the subject used for the PRNG. [128,160)
+	(data (i32.const 128)
+		"\00\01\02\03\04\05\06\07\08\09\0A\0B\0C\0D\0E\0F"
+		"\00\01\02\03\04\05\06\07\08\09\0A\0B\0C\0D\0E\0F"
+	)
+
+	;; size of our buffer is 128 bytes
+	(data (i32.const 160) "\80")
+
+	(func $assert (param i32)
+		(block $ok
+			(br_if $ok
+				(get_local 0)
+			)
+			(unreachable)
+		)
+	)
+
+	(func (export "call")
+		;; This stores the block random seed in the buffer
+		(call $seal_random
+			(i32.const 128) ;; Pointer in memory to the start of the subject buffer
+			(i32.const 32) ;; The subject buffer's length
+			(i32.const 0) ;; Pointer to the output buffer
+			(i32.const 160) ;; Pointer to the output buffer length
+		)
+
+		;; assert len == 32
+		(call $assert
+			(i32.eq
+				(i32.load (i32.const 160))
+				(i32.const 32)
+			)
+		)
+
+		;; return the random data
+		(call $seal_return
 			(i32.const 0)
 			(i32.const 0)
 			(i32.const 32)
@@ -1526,6 +1962,40 @@ mod tests {
 )
 "#;
 
+    const CODE_EVENTS_EMITTED: &str = r#"
+(module
+	(import "seal0" "seal_deposit_event" (func $seal_deposit_event (param i32 i32 i32 i32)))
+	(import "seal0" "seal_events_emitted" (func $seal_events_emitted (result i32)))
+	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+	(import "env" "memory" (memory 1 1))
+
+	(func (export "call")
+		(call $seal_deposit_event (i32.const 0) (i32.const 0) (i32.const 8) (i32.const 4))
+		(call $seal_deposit_event (i32.const 0) (i32.const 0) (i32.const 8) (i32.const 4))
+		;; Return the count as LE encoded u32.
+		(i32.store (i32.const 0) (call $seal_events_emitted))
+		(call $seal_return (i32.const 0) (i32.const 0) (i32.const 4))
+		(unreachable)
+	)
+	(func (export "deploy"))
+
+	(data (i32.const 8) "\01\02\03\04")
+)
+"#;
+
+    #[test]
+    fn events_emitted() {
+        let output = execute(
+            CODE_EVENTS_EMITTED,
+            vec![],
+            MockExt::default(),
+            &mut GasMeter::new(GAS_LIMIT),
+        )
+        .unwrap();
+
+        assert_eq!(output.data, 2u32.encode());
+    }
+
     #[test]
     fn deposit_event() {
         let mut mock_ext = MockExt::default();
@@ -1543,6 +2013,59 @@ mod tests {
         assert!(gas_meter.gas_left() > 0);
     }
 
+    const CODE_DEPOSIT_EVENT_DATA: &str = r#"
+(module
+	(import "seal0" "seal_deposit_event" (func $seal_deposit_event (param i32 i32 i32 i32)))
+	(import "env" "memory" (memory 1 1))
+
+	(func (export "call")
+		(call $seal_deposit_event
+			(i32.const 0) ;; No topics
+			(i32.const 0)
+			(i32.const 8) ;; Pointer to the start of the data buffer
+			(i32.const 13) ;; Length of the buffer
+		)
+	)
+	(func (export "deploy"))
+
+	(data (i32.const 8) "\00\01\2A\00\00\00\00\00\00\00\E5\14\00")
+)
+"#;
+
+    #[test]
+    fn deposit_event_data_too_large() {
+        // Checks that the runtime traps if the event data exceeds `max_event_data_size`.
+        let mut mock_ext = MockExt::default();
+        mock_ext.max_event_data_size = Some(12);
+
+        assert_eq!(
+            execute(
+                CODE_DEPOSIT_EVENT_DATA,
+                vec![],
+                mock_ext,
+                &mut GasMeter::new(GAS_LIMIT)
+            ),
+            Err(ExecError {
+                error: Error::<Test>::EventDataTooLarge.into(),
+                origin: ErrorOrigin::Caller,
+            })
+        );
+
+        // Exactly at the configured cap the event is deposited.
+        let mut mock_ext = MockExt::default();
+        mock_ext.max_event_data_size = Some(13);
+        assert_matches!(
+            execute(
+                CODE_DEPOSIT_EVENT_DATA,
+                vec![],
+                &mut mock_ext,
+                &mut GasMeter::new(GAS_LIMIT)
+            ),
+            Ok(_)
+        );
+        assert_eq!(mock_ext.events.len(), 1);
+    }
+
     const CODE_DEPOSIT_EVENT_MAX_TOPICS: &str = r#"
 (module
 	(import "seal0" "seal_deposit_event" (func $seal_deposit_event (param i32 i32 i32 i32)))
@@ -1572,7 +2095,7 @@ mod tests {
 
     #[test]
     fn deposit_event_max_topics() {
-        // Checks that the runtime traps if there are more than `max_topic_events` topics.
+        // Checks that the runtime traps if there are more than `limits.event_topics` topics.
         let mut gas_meter = GasMeter::new(GAS_LIMIT);
 
         assert_eq!(
@@ -1589,6 +2112,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn deposit_event_max_topics_is_configurable() {
+        // Exactly at the configured limit the event is deposited.
+        let mut mock_ext = MockExt::default();
+        mock_ext.schedule.limits.event_topics = 5;
+        assert_matches!(
+            execute(
+                CODE_DEPOSIT_EVENT_MAX_TOPICS,
+                vec![],
+                &mut mock_ext,
+                &mut GasMeter::new(GAS_LIMIT)
+            ),
+            Ok(_)
+        );
+        assert_eq!(mock_ext.events[0].0.len(), 5);
+
+        // One below it traps.
+        let mut mock_ext = MockExt::default();
+        mock_ext.schedule.limits.event_topics = 4;
+        assert_eq!(
+            execute(
+                CODE_DEPOSIT_EVENT_MAX_TOPICS,
+                vec![],
+                mock_ext,
+                &mut GasMeter::new(GAS_LIMIT)
+            ),
+            Err(ExecError {
+                error: Error::<Test>::TooManyTopics.into(),
+                origin: ErrorOrigin::Caller,
+            })
+        );
+    }
+
     const CODE_DEPOSIT_EVENT_DUPLICATES: &str = r#"
 (module
 	(import "seal0" "seal_deposit_event" (func $seal_deposit_event (param i32 i32 i32 i32)))
@@ -1634,6 +2190,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn deposit_event_duplicates_allowed_by_schedule() {
+        let mut mock_ext = MockExt::default();
+        mock_ext.schedule.allow_duplicate_topics = true;
+
+        assert_matches!(
+            execute(
+                CODE_DEPOSIT_EVENT_DUPLICATES,
+                vec![],
+                &mut mock_ext,
+                &mut GasMeter::new(GAS_LIMIT)
+            ),
+            Ok(_)
+        );
+        assert_eq!(
+            mock_ext.events[0].0,
+            vec![
+                H256::repeat_byte(0x01),
+                H256::repeat_byte(0x02),
+                H256::repeat_byte(0x01),
+                H256::repeat_byte(0x04),
+            ]
+        );
+    }
+
     /// calls `seal_block_number` compares the result with the constant 121.
     const CODE_BLOCK_NUMBER: &str = r#"
 (module
@@ -1688,6 +2269,148 @@ mod tests {
         .unwrap();
     }
 
+    const CODE_BLOCK_HASH: &str = r#"
+(module
+	(import "seal0" "seal_input" (func $seal_input (param i32 i32)))
+	(import "seal0" "seal_block_hash" (func $seal_block_hash (param i32 i32 i32 i32)))
+	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+	(import "env" "memory" (memory 1 1))
+
+	;; [0, 8) the block number passed as input
+
+	;; [8, 12) size of the input buffer = 8 bytes
+	(data (i32.const 8) "\08")
+
+	;; [12, 16) size of the output buffer = 32 bytes
+	(data (i32.const 12) "\20")
+
+	;; [16, 48) buffer where the block hash is copied
+
+	(func (export "call")
+		(call $seal_input (i32.const 0) (i32.const 8))
+		(call $seal_block_hash
+			(i32.const 0)	;; Pointer to the block number
+			(i32.const 8)	;; Length of the block number
+			(i32.const 16)	;; Pointer to the output buffer
+			(i32.const 12)	;; Pointer to the size of the output buffer
+		)
+		(call $seal_return (i32.const 0) (i32.const 16) (i32.const 32))
+		(unreachable)
+	)
+
+	(func (export "deploy"))
+)
+"#;
+
+    #[test]
+    fn block_hash() {
+        let hash = H256::repeat_byte(0x42);
+        let mut mock_ext = MockExt::default();
+        mock_ext.block_hashes.insert(100, hash);
+
+        let output = execute(
+            CODE_BLOCK_HASH,
+            100u64.encode(),
+            &mut mock_ext,
+            &mut GasMeter::new(GAS_LIMIT),
+        )
+        .unwrap();
+        assert_eq!(output.data, hash.encode());
+
+        // Unknown block numbers yield zeroes.
+        let output = execute(
+            CODE_BLOCK_HASH,
+            7u64.encode(),
+            &mut mock_ext,
+            &mut GasMeter::new(GAS_LIMIT),
+        )
+        .unwrap();
+        assert_eq!(output.data, H256::zero().encode());
+    }
+
+    const CODE_OWN_SALT: &str = r#"
+(module
+	(import "seal0" "seal_own_salt" (func $seal_own_salt (param i32 i32)))
+	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+	(import "env" "memory" (memory 1 1))
+
+	;; size of our buffer is 32 bytes
+	(data (i32.const 32) "\20")
+
+	(func (export "call")
+		;; fill the buffer with the salt.
+		(call $seal_own_salt (i32.const 0) (i32.const 32))
+
+		;; return the salt.
+		(call $seal_return
+			(i32.const 0)
+			(i32.const 0)
+			(i32.load (i32.const 32))
+		)
+		(unreachable)
+	)
+	(func (export "deploy"))
+)
+"#;
+
+    #[test]
+    fn own_salt() {
+        let mut mock_ext = MockExt::default();
+        mock_ext.salt = b"known salt".to_vec();
+
+        let output = execute(
+            CODE_OWN_SALT,
+            vec![],
+            &mut mock_ext,
+            &mut GasMeter::new(GAS_LIMIT),
+        )
+        .unwrap();
+        assert_eq!(output.data, b"known salt".to_vec());
+    }
+
+    const CODE_RENT_PROJECTION: &str = r#"
+(module
+	(import "seal0" "seal_rent_projection" (func $seal_rent_projection (param i32 i32)))
+	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+	(import "env" "memory" (memory 1 1))
+
+	;; size of our buffer is 32 bytes
+	(data (i32.const 32) "\20")
+
+	(func (export "call")
+		;; fill the buffer with the projection.
+		(call $seal_rent_projection (i32.const 0) (i32.const 32))
+
+		;; return the projection.
+		(call $seal_return
+			(i32.const 0)
+			(i32.const 0)
+			(i32.load (i32.const 32))
+		)
+		(unreachable)
+	)
+	(func (export "deploy"))
+)
+"#;
+
+    #[test]
+    fn rent_projection() {
+        let mut mock_ext = MockExt::default();
+        mock_ext.eviction_at = Some(200);
+
+        let output = execute(
+            CODE_RENT_PROJECTION,
+            vec![],
+            &mut mock_ext,
+            &mut GasMeter::new(GAS_LIMIT),
+        )
+        .unwrap();
+        assert_eq!(
+            RentProjection::<u64>::decode(&mut &output.data[..]).unwrap(),
+            RentProjection::EvictionAt(200),
+        );
+    }
+
     const CODE_RETURN_WITH_DATA: &str = r#"
 (module
 	(import "seal0" "seal_input" (func $seal_input (param i32 i32)))
@@ -1760,6 +2483,75 @@ mod tests {
         assert!(!output.is_success());
     }
 
+    const CODE_ABORT: &str = r#"
+(module
+	(import "seal0" "seal_abort" (func $seal_abort (param i32)))
+	(import "env" "memory" (memory 1 1))
+
+	(func (export "deploy"))
+
+	(func (export "call")
+		(call $seal_abort (i32.const 7))
+		(unreachable)
+	)
+)
+"#;
+
+    #[test]
+    fn abort_reverts_with_code() {
+        let output = execute(
+            CODE_ABORT,
+            vec![],
+            MockExt::default(),
+            &mut GasMeter::new(GAS_LIMIT),
+        )
+        .unwrap();
+
+        assert_eq!(output.flags, ReturnFlags::REVERT);
+        assert_eq!(u32::decode(&mut &output.data[..]), Ok(7));
+    }
+
+    const CODE_INPUT_LEN: &str = r#"
+(module
+	(import "seal0" "seal_input_len" (func $seal_input_len (result i32)))
+	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+	(import "env" "memory" (memory 1 1))
+
+	(func (export "deploy"))
+
+	;; Returns the length of the input as a LE encoded u32 without ever reading the input.
+	(func (export "call")
+		(i32.store (i32.const 0) (call $seal_input_len))
+		(call $seal_return
+			(i32.const 0)	;; flags
+			(i32.const 0)	;; Pointer to the encoded length
+			(i32.const 4)	;; Length of the encoded u32
+		)
+		(unreachable)
+	)
+)
+"#;
+
+    #[test]
+    fn seal_input_len_works() {
+        let input = hex!("00112233445566778899").to_vec();
+        let output = execute(
+            CODE_INPUT_LEN,
+            input.clone(),
+            MockExt::default(),
+            &mut GasMeter::new(GAS_LIMIT),
+        )
+        .unwrap();
+
+        assert_eq!(
+            output,
+            ExecReturnValue {
+                flags: ReturnFlags::empty(),
+                data: (input.len() as u32).encode(),
+            }
+        );
+    }
+
     const CODE_OUT_OF_BOUNDS_ACCESS: &str = r#"
 (module
 	(import "seal0" "seal_terminate" (func $seal_terminate (param i32 i32)))
@@ -1829,4 +2621,133 @@ mod tests {
             })
         );
     }
+
+    const CODE_GROW_MEMORY_LOOP: &str = r#"
+(module
+	(import "env" "memory" (memory 1 16))
+
+	(func (export "call")
+		(loop $grow
+			(drop (memory.grow (i32.const 1)))
+			(br $grow)
+		)
+	)
+	(func (export "deploy"))
+)
+"#;
+
+    #[test]
+    fn grow_memory_runs_out_of_gas() {
+        let result = execute(
+            CODE_GROW_MEMORY_LOOP,
+            vec![],
+            MockExt::default(),
+            &mut GasMeter::new(GAS_LIMIT),
+        );
+
+        assert_eq!(
+            result,
+            Err(ExecError {
+                error: Error::<Test>::OutOfGas.into(),
+                origin: ErrorOrigin::Caller,
+            })
+        );
+    }
+
+    fn code_grow_memory(pages: u32) -> String {
+        format!(
+            r#"
+(module
+	(import "env" "memory" (memory 1 16))
+
+	(func (export "call")
+		(drop (memory.grow (i32.const {})))
+	)
+	(func (export "deploy"))
+)
+"#,
+            pages
+        )
+    }
+
+    #[test]
+    fn grow_memory_is_charged_per_page() {
+        let gas_spent = |pages| {
+            let mut gas_meter = GasMeter::new(GAS_LIMIT);
+            execute(
+                &code_grow_memory(pages),
+                vec![],
+                MockExt::default(),
+                &mut gas_meter,
+            )
+            .unwrap();
+            gas_meter.gas_spent()
+        };
+        let per_page = MockExt::default()
+            .schedule()
+            .instruction_weights
+            .memory_grow_per_page;
+
+        assert!(per_page > 0);
+        assert_eq!(gas_spent(15) - gas_spent(0), 15 * Gas::from(per_page));
+    }
+
+    const CODE_CALL_RUNTIME: &str = r#"
+(module
+	(import "seal0" "seal_input" (func $seal_input (param i32 i32)))
+	(import "seal0" "seal_call_runtime" (func $seal_call_runtime (param i32 i32) (result i32)))
+	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+	(import "env" "memory" (memory 1 1))
+
+	;; 0x1000 = 4k in little endian
+	;; Size of input buffer
+	(data (i32.const 0) "\00\10")
+
+	(func (export "call")
+		;; Receive the encoded call
+		(call $seal_input
+			(i32.const 4)	;; Pointer to the input buffer
+			(i32.const 0)	;; Pointer to the size of the input buffer
+		)
+		;; Dispatch the call passed as input and store the return code
+		(i32.store
+			(i32.const 0)
+			(call $seal_call_runtime
+				(i32.const 4)				;; Pointer where the call is stored
+				(i32.load (i32.const 0))	;; Size of the call
+			)
+		)
+		(call $seal_return
+			(i32.const 0)	;; flags
+			(i32.const 0)	;; returned value
+			(i32.const 4)	;; length of returned value
+		)
+	)
+
+	(func (export "deploy"))
+)
+"#;
+
+    #[test]
+    fn call_runtime_works() {
+        let call = Call::System(frame_system::Call::remark(b"Hello World".to_vec()));
+        let mut mock_ext = MockExt::default();
+        mock_ext.schedule.enable_call_runtime = true;
+        let result = execute(
+            CODE_CALL_RUNTIME,
+            call.encode(),
+            &mut mock_ext,
+            &mut GasMeter::new(GAS_LIMIT),
+        )
+        .unwrap();
+
+        assert_eq!(
+            result,
+            ExecReturnValue {
+                flags: ReturnFlags::empty(),
+                data: (ReturnCode::Success as u32).encode(),
+            }
+        );
+        assert_eq!(*mock_ext.runtime_calls.borrow(), vec![DispatchEntry(call)]);
+    }
 }
@@ -360,6 +360,12 @@ impl<'a, T: Config> ContractModule<'a, T> {
                 return Err("module imports `seal_println` but debug features disabled");
             }
 
+            if !self.schedule.enable_call_runtime
+                && import.field().as_bytes() == b"seal_call_runtime"
+            {
+                return Err("module imports `seal_call_runtime` but it is disabled");
+            }
+
             if !T::ChainExtension::enabled()
                 && import.field().as_bytes() == b"seal_call_chain_extension"
             {
@@ -1003,6 +1009,19 @@ mod tests {
             Err("module imports `seal_println` but debug features disabled")
         );
 
+        prepare_test!(
+            seal_call_runtime_disabled,
+            r#"
+			(module
+				(import "seal0" "seal_call_runtime" (func $seal_call_runtime (param i32 i32) (result i32)))
+
+				(func (export "call"))
+				(func (export "deploy"))
+			)
+			"#,
+            Err("module imports `seal_call_runtime` but it is disabled")
+        );
+
         #[test]
         fn seal_println_debug_enabled() {
             let wasm = wat::parse_str(
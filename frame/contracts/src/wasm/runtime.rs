@@ -23,8 +23,13 @@ use crate::{
     wasm::env_def::ConvertibleToWasm,
     BalanceOf, CodeHash, Config, Error, HostFnWeights,
 };
-use codec::{Decode, DecodeAll, Encode};
-use frame_support::{dispatch::DispatchError, ensure};
+use codec::{Decode, DecodeAll, DecodeLimit, Encode};
+use frame_support::{
+    dispatch::DispatchError,
+    ensure,
+    traits::Get,
+    weights::{GetDispatchInfo, Weight},
+};
 use pallet_contracts_primitives::{ExecError, ExecResult, ExecReturnValue, ReturnFlags};
 use parity_wasm::elements::ValueType;
 use sp_core::crypto::UncheckedFrom;
@@ -66,6 +71,8 @@ pub enum ReturnCode {
     /// The contract that was called is either no contract at all (a plain account)
     /// or is a tombstone.
     NotCallable = 8,
+    /// The call dispatched by `seal_call_runtime` returned an error.
+    CallRuntimeReturnedError = 9,
 }
 
 impl ConvertibleToWasm for ReturnCode {
@@ -135,8 +142,16 @@ pub enum RuntimeToken {
     MeteringBlock(u32),
     /// Weight of calling `seal_caller`.
     Caller,
+    /// Weight of calling `seal_caller_is_origin`.
+    CallerIsOrigin,
+    /// Weight of calling `seal_call_depth`.
+    CallDepth,
     /// Weight of calling `seal_address`.
     Address,
+    /// Weight of calling `seal_own_salt`.
+    OwnSaltBase,
+    /// Weight of copying the salt of the current contract to the sandbox.
+    OwnSaltCopyOut(u32),
     /// Weight of calling `seal_gas_left`.
     GasLeft,
     /// Weight of calling `seal_balance`.
@@ -147,28 +162,44 @@ pub enum RuntimeToken {
     MinimumBalance,
     /// Weight of calling `seal_tombstone_deposit`.
     TombstoneDeposit,
+    /// Weight of calling `seal_code_deposit_per_byte`.
+    CodeDepositPerByte,
     /// Weight of calling `seal_rent_allowance`.
     RentAllowance,
+    /// Weight of calling `seal_rent_projection`.
+    RentProjectionBase,
+    /// Weight of copying the rent projection to the sandbox.
+    RentProjectionCopyOut(u32),
     /// Weight of calling `seal_block_number`.
     BlockNumber,
+    /// Weight of calling `seal_block_hash`.
+    BlockHash,
     /// Weight of calling `seal_now`.
     Now,
+    /// Weight of calling `seal_schedule_version`.
+    ScheduleVersion,
     /// Weight of calling `seal_weight_to_fee`.
     WeightToFee,
     /// Weight of calling `seal_input` without the weight of copying the input.
     InputBase,
     /// Weight of copying the input data for the given size.
     InputCopyOut(u32),
+    /// Weight of calling `seal_input_len`.
+    InputLen,
     /// Weight of calling `seal_return` for the given output size.
     Return(u32),
     /// Weight of calling `seal_terminate`.
     Terminate,
+    /// Weight of calling `seal_terminate_partial`.
+    TerminatePartial,
     /// Weight of calling `seal_restore_to` per number of supplied delta entries.
     RestoreTo(u32),
     /// Weight of calling `seal_random`. It includes the weight for copying the subject.
     Random,
     /// Weight of calling `seal_reposit_event` with the given number of topics and event size.
     DepositEvent { num_topic: u32, len: u32 },
+    /// Weight of calling `seal_events_emitted`.
+    EventsEmitted,
     /// Weight of calling `seal_set_rent_allowance`.
     SetRentAllowance,
     /// Weight of calling `seal_set_storage` for the given storage item size.
@@ -193,6 +224,8 @@ pub enum RuntimeToken {
     InstantiateBase { input_data_len: u32, salt_len: u32 },
     /// Weight of output received through `seal_instantiate` for the given size.
     InstantiateCopyOut(u32),
+    /// Weight of calling `seal_call_runtime` plus the weight of the dispatched call.
+    CallRuntime(Weight),
     /// Weight of calling `seal_hash_sha_256` for the given input size.
     HashSha256(u32),
     /// Weight of calling `seal_hash_keccak_256` for the given input size.
@@ -219,22 +252,36 @@ where
         match *self {
             MeteringBlock(amount) => s.gas.saturating_add(amount.into()),
             Caller => s.caller,
+            CallerIsOrigin => s.caller_is_origin,
+            CallDepth => s.call_depth,
             Address => s.address,
+            OwnSaltBase => s.own_salt,
+            OwnSaltCopyOut(len) => s.input_per_byte.saturating_mul(len.into()),
             GasLeft => s.gas_left,
             Balance => s.balance,
             ValueTransferred => s.value_transferred,
             MinimumBalance => s.minimum_balance,
             TombstoneDeposit => s.tombstone_deposit,
+            CodeDepositPerByte => s.code_deposit_per_byte,
             RentAllowance => s.rent_allowance,
+            // Reads the contract info, its code and the balance of the contract.
+            RentProjectionBase => s
+                .rent_projection
+                .saturating_add(T::DbWeight::get().reads(3)),
+            RentProjectionCopyOut(len) => s.input_per_byte.saturating_mul(len.into()),
             BlockNumber => s.block_number,
+            BlockHash => s.block_hash.saturating_add(T::DbWeight::get().reads(1)),
             Now => s.now,
+            ScheduleVersion => s.schedule_version,
             WeightToFee => s.weight_to_fee,
             InputBase => s.input,
             InputCopyOut(len) => s.input_per_byte.saturating_mul(len.into()),
+            InputLen => s.input_len,
             Return(len) => s
                 .r#return
                 .saturating_add(s.return_per_byte.saturating_mul(len.into())),
             Terminate => s.terminate,
+            TerminatePartial => s.terminate_partial,
             RestoreTo(delta) => s
                 .restore_to
                 .saturating_add(s.restore_to_per_delta.saturating_mul(delta.into())),
@@ -243,6 +290,7 @@ where
                 .deposit_event
                 .saturating_add(s.deposit_event_per_topic.saturating_mul(num_topic.into()))
                 .saturating_add(s.deposit_event_per_byte.saturating_mul(len.into())),
+            EventsEmitted => s.events_emitted,
             SetRentAllowance => s.set_rent_allowance,
             SetStorage(len) => s
                 .set_storage
@@ -267,6 +315,7 @@ where
                 )
                 .saturating_add(s.instantiate_per_salt_byte.saturating_mul(salt_len.into())),
             InstantiateCopyOut(len) => s.instantiate_per_output_byte.saturating_mul(len.into()),
+            CallRuntime(weight) => s.call_runtime.saturating_add(weight),
             HashSha256(len) => s
                 .hash_sha2_256
                 .saturating_add(s.hash_sha2_256_per_byte.saturating_mul(len.into())),
@@ -292,6 +341,9 @@ fn already_charged(_: u32) -> Option<RuntimeToken> {
     None
 }
 
+/// The maximum nesting depth allowed when decoding a call passed to `seal_call_runtime`.
+const MAX_DECODE_NESTING: u32 = 256;
+
 /// Finds duplicates in a given vector.
 ///
 /// This function has complexity of O(n log n) and no additional memory is required, although
@@ -380,6 +432,8 @@ where
             // Because panics are really undesirable in the runtime code, we treat this as
             // a trap for now. Eventually, we might want to revisit this.
             Err(sp_sandbox::Error::Module) => Err("validation error")?,
+            // The host keeps too many sandbox instances alive to create another one.
+            Err(sp_sandbox::Error::InstanceLimit) => Err("sandbox instance limit reached")?,
             // Any other kind of a trap should result in a failure.
             Err(sp_sandbox::Error::Execution) | Err(sp_sandbox::Error::OutOfBounds) => {
                 Err(Error::<E::T>::ContractTrapped)?
@@ -677,6 +731,44 @@ define_env!(Env, <E: Ext>,
         ctx.ext.set_storage(key, None).map_err(Into::into)
     },
 
+    // Apply a batch of storage writes to the contract storage.
+    //
+    // Every entry is charged like a separate `seal_set_storage` (or `seal_clear_storage` for
+    // `None` values) call. Writes are applied in order and writes that were already applied
+    // are kept if a later one fails.
+    //
+    // # Parameters
+    //
+    // - `batch_ptr`: pointer into the linear memory where the SCALE encoded
+    //   `Vec<(StorageKey, Option<Vec<u8>>)>` is placed.
+    // - `batch_len`: the length of the encoded batch in bytes.
+    //
+    // # Traps
+    //
+    // - If the batch can't be decoded.
+    // - If any value length exceeds the configured maximum value length of a storage entry.
+    seal_set_storage_batch(ctx, batch_ptr: u32, batch_len: u32) => {
+        let batch: Vec<(StorageKey, Option<Vec<u8>>)> =
+            ctx.read_sandbox_memory_as(batch_ptr, batch_len)?;
+        for (key, value) in batch {
+            match value {
+                Some(value) => {
+                    let value_len = value.len() as u32;
+                    ctx.charge_gas(RuntimeToken::SetStorage(value_len))?;
+                    if value_len > ctx.ext.max_value_size() {
+                        Err(Error::<E::T>::ValueTooLarge)?;
+                    }
+                    ctx.ext.set_storage(key, Some(value))?;
+                }
+                None => {
+                    ctx.charge_gas(RuntimeToken::ClearStorage)?;
+                    ctx.ext.set_storage(key, None)?;
+                }
+            }
+        }
+        Ok(())
+    },
+
     // Retrieve the value under the given key from storage.
     //
     // # Parameters
@@ -925,6 +1017,33 @@ define_env!(Env, <E: Ext>,
         Ok(Runtime::<E>::exec_into_return_code(instantiate_outcome.map(|(_id, retval)| retval))?)
     },
 
+    // Dispatch a runtime call with the calling contract as signed origin.
+    //
+    // Only available when `enable_call_runtime` is set in the current schedule.
+    //
+    // # Parameters
+    //
+    // - call_ptr: a pointer to the SCALE encoded call.
+    //   Should be decodable as a `T::Call`. Traps otherwise.
+    // - call_len: length of the call buffer.
+    //
+    // # Errors
+    //
+    // `ReturnCode::CallRuntimeReturnedError`
+    seal_call_runtime(ctx, call_ptr: u32, call_len: u32) -> ReturnCode => {
+        ctx.charge_gas(RuntimeToken::CopyIn(call_len))?;
+        let call_buf = ctx.read_sandbox_memory(call_ptr, call_len)?;
+        let call = <<E::T as Config>::Call>::decode_all_with_depth_limit(
+            MAX_DECODE_NESTING, &call_buf,
+        ).map_err(|_| Error::<E::T>::DecodingFailed)?;
+        ctx.charge_gas(RuntimeToken::CallRuntime(call.get_dispatch_info().weight))?;
+
+        match ctx.ext.call_runtime(call) {
+            Ok(_) => Ok(ReturnCode::Success),
+            Err(_) => Ok(ReturnCode::CallRuntimeReturnedError),
+        }
+    },
+
     // Remove the calling account and transfer remaining balance.
     //
     // This function never returns. Either the termination was successful and the
@@ -954,6 +1073,41 @@ define_env!(Env, <E: Ext>,
         Err(TrapReason::Termination)
     },
 
+    // Remove the calling account, transferring `value` to the beneficiary and the remaining
+    // balance to the origin of the call stack.
+    //
+    // This function never returns. Either the termination was successful and the
+    // execution of the destroyed contract is halted. Or it failed during the termination
+    // which is considered fatal and results in a trap + rollback.
+    //
+    // - beneficiary_ptr: a pointer to the address of the beneficiary account where `value`
+    //   is transfered to. Should be decodable as an `T::AccountId`. Traps otherwise.
+    // - beneficiary_len: length of the address buffer.
+    // - value_ptr: a pointer to the buffer with the value to transfer to the beneficiary.
+    //   Should be decodable as a `T::Balance`. Traps otherwise.
+    // - value_len: length of the value buffer.
+    //
+    // # Traps
+    //
+    // - The contract is live i.e is already on the call stack.
+    // - Failed to send the value to the beneficiary or the rest to the origin.
+    // - The deletion queue is full.
+    seal_terminate_partial(
+        ctx,
+        beneficiary_ptr: u32,
+        beneficiary_len: u32,
+        value_ptr: u32,
+        value_len: u32
+    ) => {
+        ctx.charge_gas(RuntimeToken::TerminatePartial)?;
+        let beneficiary: <<E as Ext>::T as frame_system::Config>::AccountId =
+            ctx.read_sandbox_memory_as(beneficiary_ptr, beneficiary_len)?;
+        let value: BalanceOf<<E as Ext>::T> = ctx.read_sandbox_memory_as(value_ptr, value_len)?;
+
+        ctx.ext.terminate_partial(&beneficiary, value)?;
+        Err(TrapReason::Termination)
+    },
+
     // Stores the input passed by the caller into the supplied buffer.
     //
     // The value is stored to linear memory at the address pointed to by `out_ptr`.
@@ -976,6 +1130,21 @@ define_env!(Env, <E: Ext>,
         }
     },
 
+    // Returns the length of the input passed by the caller without copying it.
+    //
+    // # Note
+    //
+    // The input is consumed by `seal_input`. Calling this function after the input was
+    // read will trigger a trap.
+    seal_input_len(ctx) -> u32 => {
+        ctx.charge_gas(RuntimeToken::InputLen)?;
+        if let Some(input) = ctx.input_data.as_ref() {
+            Ok(input.len() as u32)
+        } else {
+            Err(Error::<E::T>::InputAlreadyRead.into())
+        }
+    },
+
     // Cease contract execution and save a data buffer as a result of the execution.
     //
     // This function never retuns as it stops execution of the caller.
@@ -1001,6 +1170,19 @@ define_env!(Env, <E: Ext>,
         }))
     },
 
+    // Cease contract execution and revert with the given `code` as the output data.
+    //
+    // This is a shorthand for calling `seal_return` with the REVERT flag and the SCALE
+    // encoded `code` (a LE u32) as data.
+    seal_abort(ctx, code: u32) => {
+        let data = code.encode();
+        ctx.charge_gas(RuntimeToken::Return(data.len() as u32))?;
+        Err(TrapReason::Return(ReturnData {
+            flags: ReturnFlags::REVERT.bits(),
+            data,
+        }))
+    },
+
     // Stores the address of the caller into the supplied buffer.
     //
     // The value is stored to linear memory at the address pointed to by `out_ptr`.
@@ -1018,6 +1200,24 @@ define_env!(Env, <E: Ext>,
         )?)
     },
 
+    // Checks whether the caller of the current contract is the origin of the whole call stack.
+    //
+    // Returns `1` if this contract was called by a top-level call (i.e. initiated by an
+    // extrinsic) and `0` if it was called by another contract.
+    seal_caller_is_origin(ctx) -> u32 => {
+        ctx.charge_gas(RuntimeToken::CallerIsOrigin)?;
+        Ok(ctx.ext.caller_is_origin() as u32)
+    },
+
+    // Returns the depth of the current contract in the call stack.
+    //
+    // Returns `0` if this contract was called by a top-level call and is incremented by one
+    // for every nested cross-contract call or instantiation.
+    seal_call_depth(ctx) -> u32 => {
+        ctx.charge_gas(RuntimeToken::CallDepth)?;
+        Ok(ctx.ext.call_depth())
+    },
+
     // Stores the address of the current contract into the supplied buffer.
     //
     // The value is stored to linear memory at the address pointed to by `out_ptr`.
@@ -1031,6 +1231,23 @@ define_env!(Env, <E: Ext>,
         )?)
     },
 
+    // Stores the salt the current contract was instantiated with into the supplied buffer.
+    //
+    // The value is stored to linear memory at the address pointed to by `out_ptr`.
+    // `out_len_ptr` must point to a u32 value that describes the available space at
+    // `out_ptr`. This call overwrites it with the size of the value. If the available
+    // space at `out_ptr` is less than the size of the value a trap is triggered.
+    //
+    // The salt is only available while the constructor is running. Otherwise an
+    // empty buffer is written.
+    seal_own_salt(ctx, out_ptr: u32, out_len_ptr: u32) => {
+        ctx.charge_gas(RuntimeToken::OwnSaltBase)?;
+        let salt = ctx.ext.own_salt().to_vec();
+        Ok(ctx.write_sandbox_output(out_ptr, out_len_ptr, &salt, false, |len| {
+            Some(RuntimeToken::OwnSaltCopyOut(len))
+        })?)
+    },
+
     // Stores the price for the specified amount of gas into the supplied buffer.
     //
     // The value is stored to linear memory at the address pointed to by `out_ptr`.
@@ -1128,6 +1345,15 @@ define_env!(Env, <E: Ext>,
         )?)
     },
 
+    // Returns the version of the schedule that is currently in effect on chain.
+    //
+    // A contract can compare it against the version it was last instrumented with in order
+    // to detect that it has been re-instrumented after a runtime upgrade.
+    seal_schedule_version(ctx) -> u32 => {
+        ctx.charge_gas(RuntimeToken::ScheduleVersion)?;
+        Ok(ctx.ext.schedule().version)
+    },
+
     // Stores the minimum balance (a.k.a. existential deposit) into the supplied buffer.
     //
     // The data is encoded as T::Balance.
@@ -1160,6 +1386,22 @@ define_env!(Env, <E: Ext>,
         )?)
     },
 
+    // Stores the deposit a contract needs per byte of storage, including its code, into the
+    // supplied buffer.
+    //
+    // The value is stored to linear memory at the address pointed to by `out_ptr`.
+    // `out_len_ptr` must point to a u32 value that describes the available space at
+    // `out_ptr`. This call overwrites it with the size of the value. If the available
+    // space at `out_ptr` is less than the size of the value a trap is triggered.
+    //
+    // The data is encoded as T::Balance.
+    seal_code_deposit_per_byte(ctx, out_ptr: u32, out_len_ptr: u32) => {
+        ctx.charge_gas(RuntimeToken::CodeDepositPerByte)?;
+        Ok(ctx.write_sandbox_output(
+            out_ptr, out_len_ptr, &ctx.ext.code_deposit_per_byte().encode(), false, already_charged
+        )?)
+    },
+
     // Try to restore the given destination contract sacrificing the caller.
     //
     // This function will compute a tombstone hash from the caller's storage and the given code hash
@@ -1246,7 +1488,7 @@ define_env!(Env, <E: Ext>,
     //   is ignored if `topics_len` is set to 0. The topics list can't contain duplicates.
     // - topics_len - the length of the topics buffer. Pass 0 if you want to pass an empty vector.
     // - data_ptr - a pointer to a raw data buffer which will saved along the event.
-    // - data_len - the length of the data buffer.
+    // - data_len - the length of the data buffer. Traps if it exceeds `max_event_data_size`.
     seal_deposit_event(ctx, topics_ptr: u32, topics_len: u32, data_ptr: u32, data_len: u32) => {
         let num_topic = topics_len
             .checked_div(sp_std::mem::size_of::<TopicOf<E::T>>() as u32)
@@ -1255,8 +1497,8 @@ define_env!(Env, <E: Ext>,
             num_topic,
             len: data_len,
         })?;
-        if data_len > ctx.ext.max_value_size() {
-            Err(Error::<E::T>::ValueTooLarge)?;
+        if data_len > ctx.ext.max_event_data_size() {
+            Err(Error::<E::T>::EventDataTooLarge)?;
         }
 
         let mut topics: Vec::<TopicOf<<E as Ext>::T>> = match topics_len {
@@ -1269,8 +1511,8 @@ define_env!(Env, <E: Ext>,
             Err(Error::<E::T>::TooManyTopics)?;
         }
 
-        // Check for duplicate topics. If there are any, then trap.
-        if has_duplicates(&mut topics) {
+        // Check for duplicate topics unless the schedule allows them. If there are any, then trap.
+        if !ctx.ext.schedule().allow_duplicate_topics && has_duplicates(&mut topics) {
             Err(Error::<E::T>::DuplicateTopics)?;
         }
 
@@ -1281,6 +1523,12 @@ define_env!(Env, <E: Ext>,
         Ok(())
     },
 
+    // Returns the number of events deposited by the current contract so far.
+    seal_events_emitted(ctx) -> u32 => {
+        ctx.charge_gas(RuntimeToken::EventsEmitted)?;
+        Ok(ctx.ext.events_emitted())
+    },
+
     // Set rent allowance of the contract
     //
     // - value_ptr: a pointer to the buffer with value, how much to allow for rent
@@ -1310,6 +1558,22 @@ define_env!(Env, <E: Ext>,
         )?)
     },
 
+    // Stores the projected eviction of the current contract into the supplied buffer.
+    //
+    // The value is stored to linear memory at the address pointed to by `out_ptr`.
+    // `out_len_ptr` must point to a u32 value that describes the available space at
+    // `out_ptr`. This call overwrites it with the size of the value. If the available
+    // space at `out_ptr` is less than the size of the value a trap is triggered.
+    //
+    // The data is encoded as `RentProjection<T::BlockNumber>`.
+    seal_rent_projection(ctx, out_ptr: u32, out_len_ptr: u32) => {
+        ctx.charge_gas(RuntimeToken::RentProjectionBase)?;
+        let projection = ctx.ext.rent_projection().encode();
+        Ok(ctx.write_sandbox_output(out_ptr, out_len_ptr, &projection, false, |len| {
+            Some(RuntimeToken::RentProjectionCopyOut(len))
+        })?)
+    },
+
     // Prints utf8 encoded string from the data buffer.
     // Only available on `--dev` chains.
     // This function may be removed at any time, superseded by a more general contract debugging feature.
@@ -1334,6 +1598,31 @@ define_env!(Env, <E: Ext>,
         )?)
     },
 
+    // Stores the hash of the block with the given number into the supplied buffer.
+    //
+    // The block number is read from `block_number_ptr` and is encoded as T::BlockNumber.
+    // Only the hashes of recent blocks are available. For any other block number the
+    // buffer is filled with zeroes.
+    //
+    // The value is stored to linear memory at the address pointed to by `out_ptr`.
+    // `out_len_ptr` must point to a u32 value that describes the available space at
+    // `out_ptr`. This call overwrites it with the size of the value. If the available
+    // space at `out_ptr` is less than the size of the value a trap is triggered.
+    seal_block_hash(
+        ctx,
+        block_number_ptr: u32,
+        block_number_len: u32,
+        out_ptr: u32,
+        out_len_ptr: u32
+    ) => {
+        ctx.charge_gas(RuntimeToken::BlockHash)?;
+        let number = ctx.read_sandbox_memory_as(block_number_ptr, block_number_len)?;
+        let hash = ctx.ext.block_hash(number).unwrap_or_default();
+        Ok(ctx.write_sandbox_output(
+            out_ptr, out_len_ptr, &hash.encode(), false, already_charged
+        )?)
+    },
+
     // Computes the SHA2 256-bit hash on the given input buffer.
     //
     // Returns the result directly into the given output buffer.
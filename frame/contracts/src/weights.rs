@@ -18,8 +18,8 @@
 //! Autogenerated weights for pallet_contracts
 //!
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 2.0.1
-//! DATE: 2021-02-04, STEPS: [50, ], REPEAT: 20, LOW RANGE: [], HIGH RANGE: []
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 128
+//! DATE: 2026-10-17, STEPS: [50, ], REPEAT: 20, LOW RANGE: [], HIGH RANGE: []
+//! EXECUTION: Some(Native), WASM-EXECUTION: Interpreted, CHAIN: Some("dev"), DB CACHE: 128
 
 // Executed Command:
 // target/release/substrate
@@ -29,8 +29,7 @@
 // --repeat=20
 // --pallet=pallet_contracts
 // --extrinsic=*
-// --execution=wasm
-// --wasm-execution=compiled
+// --execution=native
 // --heap-pages=4096
 // --output=./frame/contracts/src/weights.rs
 // --template=./.maintain/frame-weight-template.hbs
@@ -55,27 +54,37 @@ pub trait WeightInfo {
     fn call() -> Weight;
     fn claim_surcharge() -> Weight;
     fn seal_caller(r: u32) -> Weight;
+    fn seal_caller_is_origin(r: u32) -> Weight;
+    fn seal_call_depth(r: u32) -> Weight;
     fn seal_address(r: u32) -> Weight;
+    fn seal_own_salt(r: u32) -> Weight;
     fn seal_gas_left(r: u32) -> Weight;
     fn seal_balance(r: u32) -> Weight;
     fn seal_value_transferred(r: u32) -> Weight;
     fn seal_minimum_balance(r: u32) -> Weight;
     fn seal_tombstone_deposit(r: u32) -> Weight;
+    fn seal_code_deposit_per_byte(r: u32) -> Weight;
     fn seal_rent_allowance(r: u32) -> Weight;
+    fn seal_rent_projection(r: u32) -> Weight;
     fn seal_block_number(r: u32) -> Weight;
+    fn seal_block_hash(r: u32) -> Weight;
     fn seal_now(r: u32) -> Weight;
+    fn seal_schedule_version(r: u32) -> Weight;
     fn seal_weight_to_fee(r: u32) -> Weight;
     fn seal_gas(r: u32) -> Weight;
     fn seal_input(r: u32) -> Weight;
     fn seal_input_per_kb(n: u32) -> Weight;
+    fn seal_input_len(r: u32) -> Weight;
     fn seal_return(r: u32) -> Weight;
     fn seal_return_per_kb(n: u32) -> Weight;
     fn seal_terminate(r: u32) -> Weight;
+    fn seal_terminate_partial(r: u32) -> Weight;
     fn seal_restore_to(r: u32) -> Weight;
     fn seal_restore_to_per_delta(d: u32) -> Weight;
     fn seal_random(r: u32) -> Weight;
     fn seal_deposit_event(r: u32) -> Weight;
     fn seal_deposit_event_per_topic_and_kb(t: u32, n: u32) -> Weight;
+    fn seal_events_emitted(r: u32) -> Weight;
     fn seal_set_rent_allowance(r: u32) -> Weight;
     fn seal_set_storage(r: u32) -> Weight;
     fn seal_set_storage_per_kb(n: u32) -> Weight;
@@ -114,6 +123,7 @@ pub trait WeightInfo {
     fn instr_global_set(r: u32) -> Weight;
     fn instr_memory_current(r: u32) -> Weight;
     fn instr_memory_grow(r: u32) -> Weight;
+    fn instr_memory_grow_per_page(p: u32) -> Weight;
     fn instr_i64clz(r: u32) -> Weight;
     fn instr_i64ctz(r: u32) -> Weight;
     fn instr_i64popcnt(r: u32) -> Weight;
@@ -206,12 +216,30 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add((275_938_000 as Weight).saturating_mul(r as Weight))
             .saturating_add(T::DbWeight::get().reads(5 as Weight))
     }
+    fn seal_caller_is_origin(r: u32) -> Weight {
+        (44_438_000 as Weight)
+            // Standard Error: 508_000
+            .saturating_add((25_266_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add(T::DbWeight::get().reads(5 as Weight))
+    }
+    fn seal_call_depth(r: u32) -> Weight {
+        (109_953_000 as Weight)
+            // Standard Error: 104_000
+            .saturating_add((17_801_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add(T::DbWeight::get().reads(5 as Weight))
+    }
     fn seal_address(r: u32) -> Weight {
         (132_674_000 as Weight)
             // Standard Error: 158_000
             .saturating_add((273_808_000 as Weight).saturating_mul(r as Weight))
             .saturating_add(T::DbWeight::get().reads(5 as Weight))
     }
+    fn seal_own_salt(r: u32) -> Weight {
+        (152_981_000 as Weight)
+            // Standard Error: 399_000
+            .saturating_add((44_137_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add(T::DbWeight::get().reads(5 as Weight))
+    }
     fn seal_gas_left(r: u32) -> Weight {
         (126_819_000 as Weight)
             // Standard Error: 145_000
@@ -242,24 +270,48 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add((268_280_000 as Weight).saturating_mul(r as Weight))
             .saturating_add(T::DbWeight::get().reads(5 as Weight))
     }
+    fn seal_code_deposit_per_byte(r: u32) -> Weight {
+        (134_251_000 as Weight)
+            // Standard Error: 239_000
+            .saturating_add((48_394_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add(T::DbWeight::get().reads(5 as Weight))
+    }
     fn seal_rent_allowance(r: u32) -> Weight {
         (133_306_000 as Weight)
             // Standard Error: 208_000
             .saturating_add((604_235_000 as Weight).saturating_mul(r as Weight))
             .saturating_add(T::DbWeight::get().reads(5 as Weight))
     }
+    fn seal_rent_projection(r: u32) -> Weight {
+        (0 as Weight)
+            // Standard Error: 3_039_000
+            .saturating_add((976_959_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add(T::DbWeight::get().reads(5 as Weight))
+    }
     fn seal_block_number(r: u32) -> Weight {
         (133_689_000 as Weight)
             // Standard Error: 115_000
             .saturating_add((267_107_000 as Weight).saturating_mul(r as Weight))
             .saturating_add(T::DbWeight::get().reads(5 as Weight))
     }
+    fn seal_block_hash(r: u32) -> Weight {
+        (332_870_000 as Weight)
+            // Standard Error: 3_578_000
+            .saturating_add((140_290_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add(T::DbWeight::get().reads(6 as Weight))
+    }
     fn seal_now(r: u32) -> Weight {
         (133_773_000 as Weight)
             // Standard Error: 130_000
             .saturating_add((268_897_000 as Weight).saturating_mul(r as Weight))
             .saturating_add(T::DbWeight::get().reads(5 as Weight))
     }
+    fn seal_schedule_version(r: u32) -> Weight {
+        (102_510_000 as Weight)
+            // Standard Error: 57_000
+            .saturating_add((18_845_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add(T::DbWeight::get().reads(5 as Weight))
+    }
     fn seal_weight_to_fee(r: u32) -> Weight {
         (133_222_000 as Weight)
             // Standard Error: 476_000
@@ -284,6 +336,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add((274_000 as Weight).saturating_mul(n as Weight))
             .saturating_add(T::DbWeight::get().reads(5 as Weight))
     }
+    fn seal_input_len(r: u32) -> Weight {
+        (66_150_000 as Weight)
+            // Standard Error: 184_000
+            .saturating_add((18_936_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add(T::DbWeight::get().reads(5 as Weight))
+    }
     fn seal_return(r: u32) -> Weight {
         (118_710_000 as Weight)
             // Standard Error: 77_000
@@ -304,6 +362,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(r as Weight)))
             .saturating_add(T::DbWeight::get().writes((5 as Weight).saturating_mul(r as Weight)))
     }
+    fn seal_terminate_partial(r: u32) -> Weight {
+        (110_903_000 as Weight)
+            // Standard Error: 4_780_000
+            .saturating_add((92_625_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add(T::DbWeight::get().reads(5 as Weight))
+            .saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(r as Weight)))
+            .saturating_add(T::DbWeight::get().writes((6 as Weight).saturating_mul(r as Weight)))
+    }
     fn seal_restore_to(r: u32) -> Weight {
         (219_195_000 as Weight)
             // Standard Error: 361_000
@@ -343,6 +409,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().reads((100 as Weight).saturating_mul(t as Weight)))
             .saturating_add(T::DbWeight::get().writes((100 as Weight).saturating_mul(t as Weight)))
     }
+    fn seal_events_emitted(r: u32) -> Weight {
+        (97_426_000 as Weight)
+            // Standard Error: 86_000
+            .saturating_add((19_199_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add(T::DbWeight::get().reads(5 as Weight))
+    }
     fn seal_set_rent_allowance(r: u32) -> Weight {
         (140_588_000 as Weight)
             // Standard Error: 228_000
@@ -574,9 +646,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add((3_494_000 as Weight).saturating_mul(r as Weight))
     }
     fn instr_memory_grow(r: u32) -> Weight {
-        (25_078_000 as Weight)
-            // Standard Error: 4_213_000
-            .saturating_add((2_324_209_000 as Weight).saturating_mul(r as Weight))
+        (34_877_000 as Weight)
+            // Standard Error: 367_000
+            .saturating_add((31_590_000 as Weight).saturating_mul(r as Weight))
+    }
+    fn instr_memory_grow_per_page(p: u32) -> Weight {
+        (36_125_000 as Weight)
+            // Standard Error: 21_000
+            .saturating_add((85_000 as Weight).saturating_mul(p as Weight))
     }
     fn instr_i64clz(r: u32) -> Weight {
         (24_301_000 as Weight)
@@ -797,12 +874,30 @@ impl WeightInfo for () {
             .saturating_add((275_938_000 as Weight).saturating_mul(r as Weight))
             .saturating_add(RocksDbWeight::get().reads(5 as Weight))
     }
+    fn seal_caller_is_origin(r: u32) -> Weight {
+        (44_438_000 as Weight)
+            // Standard Error: 508_000
+            .saturating_add((25_266_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add(RocksDbWeight::get().reads(5 as Weight))
+    }
+    fn seal_call_depth(r: u32) -> Weight {
+        (109_953_000 as Weight)
+            // Standard Error: 104_000
+            .saturating_add((17_801_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add(RocksDbWeight::get().reads(5 as Weight))
+    }
     fn seal_address(r: u32) -> Weight {
         (132_674_000 as Weight)
             // Standard Error: 158_000
             .saturating_add((273_808_000 as Weight).saturating_mul(r as Weight))
             .saturating_add(RocksDbWeight::get().reads(5 as Weight))
     }
+    fn seal_own_salt(r: u32) -> Weight {
+        (152_981_000 as Weight)
+            // Standard Error: 399_000
+            .saturating_add((44_137_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add(RocksDbWeight::get().reads(5 as Weight))
+    }
     fn seal_gas_left(r: u32) -> Weight {
         (126_819_000 as Weight)
             // Standard Error: 145_000
@@ -833,24 +928,48 @@ impl WeightInfo for () {
             .saturating_add((268_280_000 as Weight).saturating_mul(r as Weight))
             .saturating_add(RocksDbWeight::get().reads(5 as Weight))
     }
+    fn seal_code_deposit_per_byte(r: u32) -> Weight {
+        (134_251_000 as Weight)
+            // Standard Error: 239_000
+            .saturating_add((48_394_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add(RocksDbWeight::get().reads(5 as Weight))
+    }
     fn seal_rent_allowance(r: u32) -> Weight {
         (133_306_000 as Weight)
             // Standard Error: 208_000
             .saturating_add((604_235_000 as Weight).saturating_mul(r as Weight))
             .saturating_add(RocksDbWeight::get().reads(5 as Weight))
     }
+    fn seal_rent_projection(r: u32) -> Weight {
+        (0 as Weight)
+            // Standard Error: 3_039_000
+            .saturating_add((976_959_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add(RocksDbWeight::get().reads(5 as Weight))
+    }
     fn seal_block_number(r: u32) -> Weight {
         (133_689_000 as Weight)
             // Standard Error: 115_000
             .saturating_add((267_107_000 as Weight).saturating_mul(r as Weight))
             .saturating_add(RocksDbWeight::get().reads(5 as Weight))
     }
+    fn seal_block_hash(r: u32) -> Weight {
+        (332_870_000 as Weight)
+            // Standard Error: 3_578_000
+            .saturating_add((140_290_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add(RocksDbWeight::get().reads(6 as Weight))
+    }
     fn seal_now(r: u32) -> Weight {
         (133_773_000 as Weight)
             // Standard Error: 130_000
             .saturating_add((268_897_000 as Weight).saturating_mul(r as Weight))
             .saturating_add(RocksDbWeight::get().reads(5 as Weight))
     }
+    fn seal_schedule_version(r: u32) -> Weight {
+        (102_510_000 as Weight)
+            // Standard Error: 57_000
+            .saturating_add((18_845_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add(RocksDbWeight::get().reads(5 as Weight))
+    }
     fn seal_weight_to_fee(r: u32) -> Weight {
         (133_222_000 as Weight)
             // Standard Error: 476_000
@@ -875,6 +994,12 @@ impl WeightInfo for () {
             .saturating_add((274_000 as Weight).saturating_mul(n as Weight))
             .saturating_add(RocksDbWeight::get().reads(5 as Weight))
     }
+    fn seal_input_len(r: u32) -> Weight {
+        (66_150_000 as Weight)
+            // Standard Error: 184_000
+            .saturating_add((18_936_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add(RocksDbWeight::get().reads(5 as Weight))
+    }
     fn seal_return(r: u32) -> Weight {
         (118_710_000 as Weight)
             // Standard Error: 77_000
@@ -895,6 +1020,14 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(r as Weight)))
             .saturating_add(RocksDbWeight::get().writes((5 as Weight).saturating_mul(r as Weight)))
     }
+    fn seal_terminate_partial(r: u32) -> Weight {
+        (110_903_000 as Weight)
+            // Standard Error: 4_780_000
+            .saturating_add((92_625_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add(RocksDbWeight::get().reads(5 as Weight))
+            .saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(r as Weight)))
+            .saturating_add(RocksDbWeight::get().writes((6 as Weight).saturating_mul(r as Weight)))
+    }
     fn seal_restore_to(r: u32) -> Weight {
         (219_195_000 as Weight)
             // Standard Error: 361_000
@@ -938,6 +1071,12 @@ impl WeightInfo for () {
                 RocksDbWeight::get().writes((100 as Weight).saturating_mul(t as Weight)),
             )
     }
+    fn seal_events_emitted(r: u32) -> Weight {
+        (97_426_000 as Weight)
+            // Standard Error: 86_000
+            .saturating_add((19_199_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add(RocksDbWeight::get().reads(5 as Weight))
+    }
     fn seal_set_rent_allowance(r: u32) -> Weight {
         (140_588_000 as Weight)
             // Standard Error: 228_000
@@ -1179,9 +1318,14 @@ impl WeightInfo for () {
             .saturating_add((3_494_000 as Weight).saturating_mul(r as Weight))
     }
     fn instr_memory_grow(r: u32) -> Weight {
-        (25_078_000 as Weight)
-            // Standard Error: 4_213_000
-            .saturating_add((2_324_209_000 as Weight).saturating_mul(r as Weight))
+        (34_877_000 as Weight)
+            // Standard Error: 367_000
+            .saturating_add((31_590_000 as Weight).saturating_mul(r as Weight))
+    }
+    fn instr_memory_grow_per_page(p: u32) -> Weight {
+        (36_125_000 as Weight)
+            // Standard Error: 21_000
+            .saturating_add((85_000 as Weight).saturating_mul(p as Weight))
     }
     fn instr_i64clz(r: u32) -> Weight {
         (24_301_000 as Weight)
@@ -80,3 +80,23 @@ fn pre_image() {
         }
     })
 }
+
+#[test]
+fn vote_from_bits_checked_works() {
+    for conviction in 0u8..=6 {
+        for &aye in &[false, true] {
+            let byte = conviction | if aye { 0b1000_0000 } else { 0 };
+            let vote = Vote::from_bits_checked(byte).unwrap();
+            assert!(vote.is_valid());
+            assert_eq!(vote.aye, aye);
+            assert_eq!(u8::from(vote.conviction), conviction);
+            assert_eq!(vote.encode(), vec![byte]);
+        }
+    }
+
+    // reserved conviction values.
+    for conviction in 7u8..=0b0111_1111 {
+        assert_eq!(Vote::from_bits_checked(conviction), None);
+        assert_eq!(Vote::from_bits_checked(conviction | 0b1000_0000), None);
+    }
+}
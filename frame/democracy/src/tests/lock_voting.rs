@@ -414,3 +414,14 @@ fn locks_should_persist_from_delegation_to_voting() {
         assert_eq!(Balances::locks(5), vec![]);
     });
 }
+
+#[test]
+fn conviction_lock_periods_double_with_each_step() {
+    assert_eq!(Conviction::None.lock_periods(), 0);
+    assert_eq!(Conviction::Locked1x.lock_periods(), 1);
+    assert_eq!(Conviction::Locked2x.lock_periods(), 2);
+    assert_eq!(Conviction::Locked3x.lock_periods(), 4);
+    assert_eq!(Conviction::Locked4x.lock_periods(), 8);
+    assert_eq!(Conviction::Locked5x.lock_periods(), 16);
+    assert_eq!(Conviction::Locked6x.lock_periods(), 32);
+}
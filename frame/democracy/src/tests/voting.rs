@@ -209,3 +209,38 @@ fn passing_low_turnout_voting_should_work() {
         assert_eq!(Balances::free_balance(42), 2);
     });
 }
+
+#[test]
+fn tally_try_new_rejects_zero_balance() {
+    let vote = Vote {
+        aye: true,
+        conviction: Conviction::Locked1x,
+    };
+    assert_eq!(Tally::<u64>::try_new(vote, 0), None);
+    assert_eq!(Tally::try_new(vote, 10), Some(Tally::new(vote, 10u64)));
+}
+
+#[test]
+fn tally_merge_and_is_empty_work() {
+    assert!(Tally::<u64>::default().is_empty());
+
+    let a = Tally {
+        ayes: 1,
+        nays: 2,
+        turnout: 3,
+    };
+    let b = Tally {
+        ayes: 10,
+        nays: u64::max_value(),
+        turnout: 30,
+    };
+    assert!(!a.is_empty());
+    assert_eq!(
+        a.saturating_merge(b),
+        Tally {
+            ayes: 11,
+            nays: u64::max_value(),
+            turnout: 33,
+        }
+    );
+}
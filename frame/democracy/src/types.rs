@@ -17,7 +17,7 @@
 
 //! Miscellaneous additional datatypes.
 
-use crate::{AccountVote, Conviction, Vote, VoteThreshold};
+use crate::{AccountVote, Approved, Conviction, Vote, VoteThreshold};
 use codec::{Decode, Encode};
 use sp_runtime::traits::{
     Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Saturating, Zero,
@@ -96,6 +96,29 @@ impl<
         }
     }
 
+    /// Create a new tally, or `None` if `balance` is zero and the vote would carry no turnout.
+    pub fn try_new(vote: Vote, balance: Balance) -> Option<Self> {
+        if balance.is_zero() {
+            None
+        } else {
+            Some(Self::new(vote, balance))
+        }
+    }
+
+    /// Whether no votes or turnout have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.ayes.is_zero() && self.nays.is_zero() && self.turnout.is_zero()
+    }
+
+    /// Combine two tallies, saturating each field.
+    pub fn saturating_merge(self, other: Self) -> Self {
+        Self {
+            ayes: self.ayes.saturating_add(other.ayes),
+            nays: self.nays.saturating_add(other.nays),
+            turnout: self.turnout.saturating_add(other.turnout),
+        }
+    }
+
     /// Add an account's vote into the tally.
     pub fn add(&mut self, vote: AccountVote<Balance>) -> Option<()> {
         match vote {
@@ -165,6 +188,54 @@ impl<
         }
         Some(())
     }
+
+    /// The additional aye votes needed for this tally to pass under `threshold`, or zero if it
+    /// already passes.
+    ///
+    /// Each additional vote is assumed to be cast with `Conviction::Locked1x`, i.e. it adds one
+    /// unit to both the ayes and the turnout. Returns `Balance::max_value()` if no amount of
+    /// additional ayes makes the tally pass.
+    pub fn ayes_needed(&self, threshold: VoteThreshold, electorate: Balance) -> Balance
+    where
+        Balance: Ord,
+        VoteThreshold: Approved<Balance>,
+    {
+        let passes = |extra: Balance| {
+            let tally = Self {
+                ayes: self.ayes.saturating_add(extra),
+                nays: self.nays,
+                turnout: self.turnout.saturating_add(extra),
+            };
+            threshold.approved(tally, electorate)
+        };
+        if passes(Zero::zero()) {
+            return Zero::zero();
+        }
+
+        // Double the amount until the tally passes, then bisect between the last two amounts.
+        let two = Balance::from(2u8);
+        let mut high = Balance::from(1u8);
+        while !passes(high) {
+            if high == Balance::max_value() {
+                return high;
+            }
+            high = high.saturating_mul(two);
+        }
+        let mut low = high.checked_div(&two).unwrap_or_else(Zero::zero);
+        while high.saturating_sub(low) > Balance::from(1u8) {
+            let mid = low.saturating_add(
+                high.saturating_sub(low)
+                    .checked_div(&two)
+                    .unwrap_or_else(Zero::zero),
+            );
+            if passes(mid) {
+                high = mid;
+            } else {
+                low = mid;
+            }
+        }
+        high
+    }
 }
 
 /// Info regarding an ongoing referendum.
@@ -43,10 +43,25 @@ impl EncodeLike for Vote {}
 impl Decode for Vote {
     fn decode<I: Input>(input: &mut I) -> Result<Self, codec::Error> {
         let b = input.read_byte()?;
-        Ok(Vote {
-            aye: (b & 0b1000_0000) == 0b1000_0000,
-            conviction: Conviction::try_from(b & 0b0111_1111)
-                .map_err(|_| codec::Error::from("Invalid conviction"))?,
+        Vote::from_bits_checked(b).ok_or_else(|| codec::Error::from("Invalid conviction"))
+    }
+}
+
+impl Vote {
+    /// Whether the conviction of this vote is one of the known variants, i.e. whether it survives
+    /// an encoding round-trip.
+    pub fn is_valid(&self) -> bool {
+        Conviction::try_from(u8::from(self.conviction)).is_ok()
+    }
+
+    /// Decode a vote from its packed on-chain representation: the highest bit is `aye`, the
+    /// remaining bits are the conviction.
+    ///
+    /// Returns `None` if the conviction bits hold a reserved value.
+    pub fn from_bits_checked(byte: u8) -> Option<Vote> {
+        Some(Vote {
+            aye: (byte & 0b1000_0000) == 0b1000_0000,
+            conviction: Conviction::try_from(byte & 0b0111_1111).ok()?,
         })
     }
 }
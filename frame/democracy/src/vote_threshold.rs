@@ -129,4 +129,31 @@ mod tests {
             210
         ));
     }
+
+    #[test]
+    fn ayes_needed_works() {
+        let tally = Tally {
+            ayes: 60,
+            nays: 50,
+            turnout: 110,
+        };
+        let needed = tally.ayes_needed(VoteThreshold::SuperMajorityApprove, 210);
+        assert_eq!(needed, 11);
+        let with_ayes = |extra| Tally {
+            ayes: 60 + extra,
+            nays: 50,
+            turnout: 110 + extra,
+        };
+        assert!(VoteThreshold::SuperMajorityApprove.approved(with_ayes(needed), 210));
+        assert!(!VoteThreshold::SuperMajorityApprove.approved(with_ayes(needed - 1), 210));
+
+        // A simple majority is already reached.
+        assert_eq!(tally.ayes_needed(VoteThreshold::SimpleMajority, 210), 0);
+        let tally = Tally {
+            ayes: 40,
+            nays: 50,
+            turnout: 90,
+        };
+        assert_eq!(tally.ayes_needed(VoteThreshold::SimpleMajority, 210), 11);
+    }
 }
@@ -159,12 +159,16 @@ impl pallet_balances::Config for Test {
 
 parameter_types! {
     pub const MinimumPeriod: u64 = 3;
+    pub const MedianWindow: u32 = 5;
+    pub const MaxPastDriftMillis: u64 = u64::max_value();
 }
 
 impl pallet_timestamp::Config for Test {
     type Moment = u64;
     type OnTimestampSet = ();
     type MinimumPeriod = MinimumPeriod;
+    type MedianWindow = MedianWindow;
+    type MaxPastDriftMillis = MaxPastDriftMillis;
     type WeightInfo = ();
 }
 
@@ -223,6 +227,7 @@ impl pallet_offences::Config for Test {
     type Event = Event;
     type IdentificationTuple = pallet_session::historical::IdentificationTuple<Self>;
     type OnOffenceHandler = Staking;
+    type OnReport = ();
     type WeightSoftLimit = OffencesWeightSoftLimit;
 }
 
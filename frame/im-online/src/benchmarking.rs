@@ -62,10 +62,9 @@ pub fn create_heartbeat<T: Config>(
         validators_len: keys.len() as u32,
     };
 
-    let encoded_heartbeat = input_heartbeat.encode();
     let authority_id = keys.get((k - 1) as usize).ok_or("out of range")?;
     let signature = authority_id
-        .sign(&encoded_heartbeat)
+        .sign(&input_heartbeat.signing_payload())
         .ok_or("couldn't make signature")?;
 
     Ok((input_heartbeat, signature))
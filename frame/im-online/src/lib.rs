@@ -178,7 +178,7 @@ impl<BlockNumber: PartialEq + AtLeast32BitUnsigned + Copy> HeartbeatStatus<Block
 
 /// Error which may occur while executing the off-chain code.
 #[cfg_attr(test, derive(PartialEq))]
-enum OffchainErr<BlockNumber> {
+pub enum OffchainErr<BlockNumber> {
     TooEarly(BlockNumber),
     WaitingForInclusion(BlockNumber),
     AlreadyOnline(u32),
@@ -232,6 +232,13 @@ where
     pub validators_len: u32,
 }
 
+impl<BlockNumber: PartialEq + Eq + Decode + Encode> Heartbeat<BlockNumber> {
+    /// The bytes an authority signs to vouch for this heartbeat.
+    pub fn signing_payload(&self) -> Vec<u8> {
+        self.encode()
+    }
+}
+
 /// A type for representing the validator id in a session.
 pub type ValidatorId<T> = <<T as Config>::ValidatorSet as ValidatorSet<
     <T as frame_system::Config>::AccountId,
@@ -276,6 +283,11 @@ pub trait Config: SendTransactionTypes<Call<Self>> + frame_system::Config {
     /// multiple pallets send unsigned transactions.
     type UnsignedPriority: Get<TransactionPriority>;
 
+    /// The maximum number of external addresses a heartbeat's network state may carry.
+    ///
+    /// Heartbeats with more addresses are rejected by `validate_unsigned`.
+    type MaxNetworkStateAddresses: Get<u32>;
+
     /// Weight information for extrinsics in this pallet.
     type WeightInfo: WeightInfo;
 }
@@ -339,6 +351,9 @@ decl_module! {
     pub struct Module<T: Config> for enum Call where origin: T::Origin {
         type Error = Error<T>;
 
+        /// The maximum number of external addresses a heartbeat's network state may carry.
+        const MaxNetworkStateAddresses: u32 = T::MaxNetworkStateAddresses::get();
+
         fn deposit_event() = default;
 
         /// # <weight>
@@ -460,6 +475,29 @@ impl<T: Config> Module<T> {
         <ReceivedHeartbeats>::contains_key(&current_session, &authority_index)
     }
 
+    /// Returns all heartbeats received during `session_index`, ordered by authority index.
+    ///
+    /// Together with [`Self::restore_heartbeats`] this allows tests to reproduce a mid-session
+    /// state without replaying the heartbeat extrinsics.
+    #[cfg(feature = "std")]
+    pub fn snapshot_heartbeats(session_index: SessionIndex) -> Vec<(AuthIndex, Vec<u8>)> {
+        use frame_support::storage::IterableStorageDoubleMap;
+
+        let mut heartbeats = <ReceivedHeartbeats>::iter_prefix(&session_index).collect::<Vec<_>>();
+        heartbeats.sort_by_key(|(authority_index, _)| *authority_index);
+        heartbeats
+    }
+
+    /// Replace the heartbeats received during `session_index` with `heartbeats`, as previously
+    /// returned by [`Self::snapshot_heartbeats`].
+    #[cfg(feature = "std")]
+    pub fn restore_heartbeats(session_index: SessionIndex, heartbeats: Vec<(AuthIndex, Vec<u8>)>) {
+        <ReceivedHeartbeats>::remove_prefix(&session_index);
+        for (authority_index, network_state) in heartbeats {
+            <ReceivedHeartbeats>::insert(&session_index, &authority_index, network_state);
+        }
+    }
+
     /// Note that the given authority has authored a block in the current session.
     fn note_authorship(author: ValidatorId<T>) {
         let current_session = T::ValidatorSet::session_index();
@@ -470,6 +508,59 @@ impl<T: Config> Module<T> {
     pub(crate) fn send_heartbeats(
         block_number: T::BlockNumber,
     ) -> OffchainResult<T, impl Iterator<Item = OffchainResult<T, ()>>> {
+        let (session_index, validators_len, keys) = Self::heartbeat_keys(block_number)?;
+
+        Ok(keys.map(move |(authority_index, key)| {
+            Self::send_single_heartbeat(
+                authority_index,
+                key,
+                session_index,
+                block_number,
+                validators_len,
+            )
+        }))
+    }
+
+    /// Build and sign the heartbeats that the offchain worker would submit at `block_number`,
+    /// without submitting them or taking the per-authority heartbeat locks.
+    ///
+    /// Authorities that are already online are skipped.
+    pub fn build_heartbeats(
+        block_number: T::BlockNumber,
+    ) -> Result<
+        Vec<(
+            Heartbeat<T::BlockNumber>,
+            <T::AuthorityId as RuntimeAppPublic>::Signature,
+        )>,
+        OffchainErr<T::BlockNumber>,
+    > {
+        let (session_index, validators_len, keys) = Self::heartbeat_keys(block_number)?;
+
+        keys.filter(|(authority_index, _)| !Self::is_online(*authority_index))
+            .map(|(authority_index, key)| {
+                Self::prepare_heartbeat(
+                    authority_index,
+                    &key,
+                    session_index,
+                    block_number,
+                    validators_len,
+                )
+            })
+            .collect()
+    }
+
+    /// Check that heartbeats are due at `block_number` and return the session index and the
+    /// validator count they must carry, along with the local authority keys to sign them with.
+    fn heartbeat_keys(
+        block_number: T::BlockNumber,
+    ) -> OffchainResult<
+        T,
+        (
+            SessionIndex,
+            u32,
+            impl Iterator<Item = (u32, T::AuthorityId)>,
+        ),
+    > {
         let heartbeat_after = <HeartbeatAfter<T>>::get();
         if block_number < heartbeat_after {
             return Err(OffchainErr::TooEarly(heartbeat_after));
@@ -478,45 +569,46 @@ impl<T: Config> Module<T> {
         let session_index = T::ValidatorSet::session_index();
         let validators_len = Keys::<T>::decode_len().unwrap_or_default() as u32;
 
-        Ok(
-            Self::local_authority_keys().map(move |(authority_index, key)| {
-                Self::send_single_heartbeat(
-                    authority_index,
-                    key,
-                    session_index,
-                    block_number,
-                    validators_len,
-                )
-            }),
-        )
+        Ok((session_index, validators_len, Self::local_authority_keys()))
     }
 
-    fn send_single_heartbeat(
+    fn prepare_heartbeat(
         authority_index: u32,
-        key: T::AuthorityId,
+        key: &T::AuthorityId,
         session_index: SessionIndex,
         block_number: T::BlockNumber,
         validators_len: u32,
-    ) -> OffchainResult<T, ()> {
-        // A helper function to prepare heartbeat call.
-        let prepare_heartbeat = || -> OffchainResult<T, Call<T>> {
-            let network_state =
-                sp_io::offchain::network_state().map_err(|_| OffchainErr::NetworkState)?;
-            let heartbeat_data = Heartbeat {
-                block_number,
-                network_state,
-                session_index,
-                authority_index,
-                validators_len,
-            };
+    ) -> OffchainResult<
+        T,
+        (
+            Heartbeat<T::BlockNumber>,
+            <T::AuthorityId as RuntimeAppPublic>::Signature,
+        ),
+    > {
+        let network_state =
+            sp_io::offchain::network_state().map_err(|_| OffchainErr::NetworkState)?;
+        let heartbeat_data = Heartbeat {
+            block_number,
+            network_state,
+            session_index,
+            authority_index,
+            validators_len,
+        };
 
-            let signature = key
-                .sign(&heartbeat_data.encode())
-                .ok_or(OffchainErr::FailedSigning)?;
+        let signature = key
+            .sign(&heartbeat_data.signing_payload())
+            .ok_or(OffchainErr::FailedSigning)?;
 
-            Ok(Call::heartbeat(heartbeat_data, signature))
-        };
+        Ok((heartbeat_data, signature))
+    }
 
+    fn send_single_heartbeat(
+        authority_index: u32,
+        key: T::AuthorityId,
+        session_index: SessionIndex,
+        block_number: T::BlockNumber,
+        validators_len: u32,
+    ) -> OffchainResult<T, ()> {
         if Self::is_online(authority_index) {
             return Err(OffchainErr::AlreadyOnline(authority_index));
         }
@@ -524,7 +616,14 @@ impl<T: Config> Module<T> {
         // acquire lock for that authority at current heartbeat to make sure we don't
         // send concurrent heartbeats.
         Self::with_heartbeat_lock(authority_index, session_index, block_number, || {
-            let call = prepare_heartbeat()?;
+            let (heartbeat_data, signature) = Self::prepare_heartbeat(
+                authority_index,
+                &key,
+                session_index,
+                block_number,
+                validators_len,
+            )?;
+            let call = Call::heartbeat(heartbeat_data, signature);
             debug::info!(
                 target: "imonline",
                 "[index: {:?}] Reporting im-online at block: {:?} (session: {:?}): {:?}",
@@ -698,6 +797,12 @@ impl<T: Config> OneSessionHandler<T::AccountId> for Module<T> {
 
 /// Invalid transaction custom error. Returned when validators_len field in heartbeat is incorrect.
 const INVALID_VALIDATORS_LEN: u8 = 10;
+/// Invalid transaction custom error. Returned when the authority_index field in heartbeat does not
+/// refer to any of the current keys, e.g. because it is stale after a key change.
+const INVALID_AUTHORITY_INDEX: u8 = 11;
+/// Invalid transaction custom error. Returned when the network state of a heartbeat carries more
+/// than `MaxNetworkStateAddresses` external addresses.
+const INVALID_NETWORK_STATE_LEN: u8 = 12;
 
 impl<T: Config> frame_support::unsigned::ValidateUnsigned for Module<T> {
     type Call = Call<T>;
@@ -722,13 +827,16 @@ impl<T: Config> frame_support::unsigned::ValidateUnsigned for Module<T> {
             }
             let authority_id = match keys.get(heartbeat.authority_index as usize) {
                 Some(id) => id,
-                None => return InvalidTransaction::BadProof.into(),
+                None => return InvalidTransaction::Custom(INVALID_AUTHORITY_INDEX).into(),
             };
+            if heartbeat.network_state.external_addresses.len()
+                > T::MaxNetworkStateAddresses::get() as usize
+            {
+                return InvalidTransaction::Custom(INVALID_NETWORK_STATE_LEN).into();
+            }
 
             // check signature (this is expensive so we do it last).
-            let signature_valid = heartbeat.using_encoded(|encoded_heartbeat| {
-                authority_id.verify(&encoded_heartbeat, &signature)
-            });
+            let signature_valid = authority_id.verify(&heartbeat.signing_payload(), &signature);
 
             if !signature_valid {
                 return InvalidTransaction::BadProof.into();
@@ -180,6 +180,7 @@ impl pallet_authorship::Config for Runtime {
 
 parameter_types! {
     pub const UnsignedPriority: u64 = 1 << 20;
+    pub const MaxNetworkStateAddresses: u32 = 100;
 }
 
 impl Config for Runtime {
@@ -189,6 +190,7 @@ impl Config for Runtime {
     type ValidatorSet = Historical;
     type SessionDuration = Period;
     type UnsignedPriority = UnsignedPriority;
+    type MaxNetworkStateAddresses = MaxNetworkStateAddresses;
     type WeightInfo = ();
 }
 
@@ -21,10 +21,10 @@
 
 use super::*;
 use crate::mock::*;
-use frame_support::{assert_noop, dispatch};
+use frame_support::{assert_noop, assert_ok, dispatch};
 use sp_core::offchain::{
     testing::{TestOffchainExt, TestTransactionPoolExt},
-    OffchainExt, TransactionPoolExt,
+    OffchainExt, OpaqueMultiaddr, TransactionPoolExt,
 };
 use sp_core::OpaquePeerId;
 use sp_runtime::{testing::UintAuthorityId, transaction_validity::TransactionValidityError};
@@ -113,6 +113,24 @@ fn heartbeat(
     authority_index: u32,
     id: UintAuthorityId,
     validators: Vec<u64>,
+) -> dispatch::DispatchResult {
+    heartbeat_with_addresses(
+        block_number,
+        session_index,
+        authority_index,
+        id,
+        validators,
+        vec![],
+    )
+}
+
+fn heartbeat_with_addresses(
+    block_number: u64,
+    session_index: u32,
+    authority_index: u32,
+    id: UintAuthorityId,
+    validators: Vec<u64>,
+    external_addresses: Vec<OpaqueMultiaddr>,
 ) -> dispatch::DispatchResult {
     use frame_support::unsigned::ValidateUnsigned;
 
@@ -120,13 +138,13 @@ fn heartbeat(
         block_number,
         network_state: OpaqueNetworkState {
             peer_id: OpaquePeerId(vec![1]),
-            external_addresses: vec![],
+            external_addresses,
         },
         session_index,
         authority_index,
         validators_len: validators.len() as u32,
     };
-    let signature = id.sign(&heartbeat.encode()).unwrap();
+    let signature = id.sign(&heartbeat.signing_payload()).unwrap();
 
     ImOnline::pre_dispatch(&crate::Call::heartbeat(
         heartbeat.clone(),
@@ -136,11 +154,33 @@ fn heartbeat(
         TransactionValidityError::Invalid(InvalidTransaction::Custom(INVALID_VALIDATORS_LEN)) => {
             "invalid validators len"
         }
+        TransactionValidityError::Invalid(InvalidTransaction::Custom(INVALID_AUTHORITY_INDEX)) => {
+            "invalid authority index"
+        }
+        TransactionValidityError::Invalid(InvalidTransaction::Custom(
+            INVALID_NETWORK_STATE_LEN,
+        )) => "invalid network state len",
         e @ _ => <&'static str>::from(e),
     })?;
     ImOnline::heartbeat(Origin::none(), heartbeat, signature)
 }
 
+#[test]
+fn signing_payload_is_the_encoded_heartbeat() {
+    let heartbeat = Heartbeat {
+        block_number: 7u64,
+        network_state: OpaqueNetworkState {
+            peer_id: OpaquePeerId(vec![1, 2]),
+            external_addresses: vec![],
+        },
+        session_index: 3,
+        authority_index: 1,
+        validators_len: 4,
+    };
+
+    assert_eq!(heartbeat.signing_payload(), heartbeat.encode());
+}
+
 #[test]
 fn should_mark_online_validator_when_heartbeat_is_received() {
     new_test_ext().execute_with(|| {
@@ -207,6 +247,52 @@ fn late_heartbeat_and_invalid_keys_len_should_fail() {
     });
 }
 
+#[test]
+fn heartbeat_with_out_of_range_authority_index_should_fail() {
+    new_test_ext().execute_with(|| {
+        advance_session();
+        VALIDATORS.with(|l| *l.borrow_mut() = Some(vec![1, 2, 3, 4, 5, 6]));
+        advance_session();
+
+        assert_eq!(Session::validators(), vec![1, 2, 3]);
+
+        // there are only three keys in the current session.
+        assert_noop!(
+            heartbeat(1, 2, 3, 1.into(), Session::validators()),
+            "invalid authority index"
+        );
+    });
+}
+
+#[test]
+fn heartbeat_with_too_many_addresses_should_fail() {
+    new_test_ext().execute_with(|| {
+        advance_session();
+        VALIDATORS.with(|l| *l.borrow_mut() = Some(vec![1, 2, 3, 4, 5, 6]));
+        advance_session();
+
+        let addresses = |count| vec![OpaqueMultiaddr(vec![1]); count];
+        let max = MaxNetworkStateAddresses::get() as usize;
+
+        assert_noop!(
+            heartbeat_with_addresses(1, 2, 0, 1.into(), Session::validators(), addresses(max + 1)),
+            "invalid network state len"
+        );
+        assert!(!ImOnline::is_online(0));
+
+        // exactly at the limit the heartbeat is accepted.
+        assert_ok!(heartbeat_with_addresses(
+            1,
+            2,
+            0,
+            1.into(),
+            Session::validators(),
+            addresses(max)
+        ));
+        assert!(ImOnline::is_online(0));
+    });
+}
+
 #[test]
 fn should_generate_heartbeats() {
     use frame_support::traits::OffchainWorker;
@@ -256,6 +342,49 @@ fn should_generate_heartbeats() {
     });
 }
 
+#[test]
+fn build_heartbeats_matches_submitted_heartbeats() {
+    use frame_support::traits::OffchainWorker;
+
+    let mut ext = new_test_ext();
+    let (offchain, _state) = TestOffchainExt::new();
+    let (pool, state) = TestTransactionPoolExt::new();
+    ext.register_extension(OffchainExt::new(offchain));
+    ext.register_extension(TransactionPoolExt::new(pool));
+
+    ext.execute_with(|| {
+        // given
+        let block = 1;
+        System::set_block_number(block);
+        UintAuthorityId::set_all_keys(vec![0, 1, 2]);
+        Session::rotate_session();
+        VALIDATORS.with(|l| *l.borrow_mut() = Some(vec![1, 2, 3, 4, 5, 6]));
+        Session::rotate_session();
+
+        // when
+        let built = ImOnline::build_heartbeats(block).unwrap();
+        // building doesn't submit anything.
+        assert!(state.read().transactions.is_empty());
+        ImOnline::offchain_worker(block);
+
+        // then
+        let submitted = state
+            .read()
+            .transactions
+            .iter()
+            .map(|transaction| {
+                let ex: Extrinsic = Decode::decode(&mut &**transaction).unwrap();
+                match ex.call {
+                    crate::mock::Call::ImOnline(crate::Call::heartbeat(h, s)) => (h, s),
+                    e => panic!("Unexpected call: {:?}", e),
+                }
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(built.len(), 3);
+        assert_eq!(built, submitted);
+    });
+}
+
 #[test]
 fn should_cleanup_received_heartbeats_on_session_end() {
     new_test_ext().execute_with(|| {
@@ -285,6 +414,36 @@ fn should_cleanup_received_heartbeats_on_session_end() {
     });
 }
 
+#[test]
+fn snapshot_and_restore_heartbeats_works() {
+    new_test_ext().execute_with(|| {
+        advance_session();
+
+        VALIDATORS.with(|l| *l.borrow_mut() = Some(vec![1, 2, 3]));
+        advance_session();
+        assert_eq!(Session::current_index(), 2);
+
+        let _ = heartbeat(1, 2, 0, 1.into(), Session::validators()).unwrap();
+        let _ = heartbeat(1, 2, 2, 3.into(), Session::validators()).unwrap();
+
+        let snapshot = ImOnline::snapshot_heartbeats(2);
+        assert_eq!(
+            snapshot.iter().map(|(index, _)| *index).collect::<Vec<_>>(),
+            vec![0, 2],
+        );
+
+        // the heartbeats are pruned once the session ends
+        advance_session();
+        assert!(ImOnline::snapshot_heartbeats(2).is_empty());
+
+        ImOnline::restore_heartbeats(2, snapshot.clone());
+        assert_eq!(ImOnline::snapshot_heartbeats(2), snapshot);
+        assert!(ImOnline::received_heartbeats(&2, &0).is_some());
+        assert!(ImOnline::received_heartbeats(&2, &1).is_none());
+        assert!(ImOnline::received_heartbeats(&2, &2).is_some());
+    });
+}
+
 #[test]
 fn should_mark_online_validator_when_block_is_authored() {
     use pallet_authorship::EventHandler;
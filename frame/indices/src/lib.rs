@@ -98,6 +98,8 @@ decl_error! {
         NotTransfer,
         /// The index is permanent and may not be freed/changed.
         Permanent,
+        /// The index is frozen and may not be transferred.
+        IndexFrozen,
     }
 }
 
@@ -143,7 +145,8 @@ decl_module! {
         ///
         /// The dispatch origin for this call must be _Signed_.
         ///
-        /// - `index`: the index to be re-assigned. This must be owned by the sender.
+        /// - `index`: the index to be re-assigned. This must be owned by the sender and not be
+        ///   frozen.
         /// - `new`: the new owner of the index. This function is a no-op if it is equal to sender.
         ///
         /// Emits `IndexAssigned` if successful.
@@ -165,7 +168,7 @@ decl_module! {
 
             Accounts::<T>::try_mutate(index, |maybe_value| -> DispatchResult {
                 let (account, amount, perm) = maybe_value.take().ok_or(Error::<T>::NotAssigned)?;
-                ensure!(!perm, Error::<T>::Permanent);
+                ensure!(!perm, Error::<T>::IndexFrozen);
                 ensure!(&account == &who, Error::<T>::NotOwner);
                 let lost = T::Currency::repatriate_reserved(&who, &new, amount, Reserved)?;
                 *maybe_value = Some((new.clone(), amount.saturating_sub(lost), false));
@@ -211,10 +214,13 @@ decl_module! {
         ///
         /// The dispatch origin for this call must be _Root_.
         ///
-        /// - `index`: the index to be (re-)assigned.
+        /// - `index`: the index to be (re-)assigned. This must not be frozen.
         /// - `new`: the new owner of the index. This function is a no-op if it is equal to sender.
         /// - `freeze`: if set to `true`, will freeze the index so it cannot be transferred.
         ///
+        /// Frozen indices are permanent: not even this call can reassign them, so an index frozen
+        /// here stays with `new` forever.
+        ///
         /// Emits `IndexAssigned` if successful.
         ///
         /// # <weight>
@@ -231,17 +237,22 @@ decl_module! {
         fn force_transfer(origin, new: T::AccountId, index: T::AccountIndex, freeze: bool) {
             ensure_root(origin)?;
 
-            Accounts::<T>::mutate(index, |maybe_value| {
-                if let Some((account, amount, _)) = maybe_value.take() {
+            Accounts::<T>::try_mutate(index, |maybe_value| -> DispatchResult {
+                if let Some((account, amount, perm)) = maybe_value.take() {
+                    ensure!(!perm, Error::<T>::IndexFrozen);
                     T::Currency::unreserve(&account, amount);
                 }
                 *maybe_value = Some((new.clone(), Zero::zero(), freeze));
-            });
+                Ok(())
+            })?;
             Self::deposit_event(RawEvent::IndexAssigned(new, index));
         }
 
         /// Freeze an index so it will always point to the sender account. This consumes the deposit.
         ///
+        /// This is permanent: a frozen index can be neither freed nor transferred, not even with
+        /// `force_transfer`.
+        ///
         /// The dispatch origin for this call must be _Signed_ and the signing account must have a
         /// non-frozen account `index`.
         ///
@@ -282,6 +293,13 @@ impl<T: Config> Module<T> {
         Accounts::<T>::get(index).map(|x| x.0)
     }
 
+    /// Lookup the deposit currently held for an T::AccountIndex, if it is claimed.
+    ///
+    /// Frozen and force-assigned indices hold no deposit.
+    pub fn deposit_of(index: T::AccountIndex) -> Option<BalanceOf<T>> {
+        Accounts::<T>::get(index).map(|x| x.1)
+    }
+
     /// Lookup an address to get an Id, if there's one there.
     pub fn lookup_address(a: MultiAddress<T::AccountId, T::AccountIndex>) -> Option<T::AccountId> {
         match a {
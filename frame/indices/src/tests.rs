@@ -37,6 +37,17 @@ fn claiming_should_work() {
     });
 }
 
+#[test]
+fn deposit_of_should_work() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(Indices::deposit_of(0), None);
+        assert_ok!(Indices::claim(Some(1).into(), 0));
+        assert_eq!(Indices::deposit_of(0), Some(Deposit::get()));
+        assert_ok!(Indices::freeze(Some(1).into(), 0));
+        assert_eq!(Indices::deposit_of(0), Some(0));
+    });
+}
+
 #[test]
 fn freeing_should_work() {
     new_test_ext().execute_with(|| {
@@ -66,8 +77,33 @@ fn freezing_should_work() {
         assert_noop!(Indices::free(Some(1).into(), 0), Error::<Test>::Permanent);
         assert_noop!(
             Indices::transfer(Some(1).into(), 2, 0),
-            Error::<Test>::Permanent
+            Error::<Test>::IndexFrozen
+        );
+    });
+}
+
+#[test]
+fn transferring_frozen_index_should_fail() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Indices::claim(Some(1).into(), 0));
+        assert_ok!(Indices::freeze(Some(1).into(), 0));
+        assert!(
+            System::events()
+                .iter()
+                .any(|record| record.event
+                    == mock::Event::pallet_indices(RawEvent::IndexFrozen(0, 1)))
+        );
+
+        assert_noop!(
+            Indices::transfer(Some(1).into(), 2, 0),
+            Error::<Test>::IndexFrozen
         );
+        assert_noop!(
+            Indices::force_transfer(Origin::root(), 2, 0, false),
+            Error::<Test>::IndexFrozen
+        );
+        assert_eq!(Indices::lookup_index(0), Some(1));
     });
 }
 
@@ -131,3 +167,22 @@ fn force_transfer_index_on_free_should_work() {
         assert_eq!(Indices::lookup_index(0), Some(3));
     });
 }
+
+#[test]
+fn force_transfer_with_freeze_is_permanent() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Indices::force_transfer(Origin::root(), 3, 0, true));
+        assert_eq!(Indices::lookup_index(0), Some(3));
+
+        assert_noop!(
+            Indices::force_transfer(Origin::root(), 2, 0, false),
+            Error::<Test>::IndexFrozen
+        );
+        assert_noop!(
+            Indices::transfer(Some(3).into(), 2, 0),
+            Error::<Test>::IndexFrozen
+        );
+        assert_noop!(Indices::free(Some(3).into(), 0), Error::<Test>::Permanent);
+        assert_eq!(Indices::lookup_index(0), Some(3));
+    });
+}
@@ -70,6 +70,30 @@ benchmarks! {
         assert_eq!(TicketsCount::get(), 1);
     }
 
+    buy_weighted_ticket {
+        let caller = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+        setup_lottery::<T>(false)?;
+        // force user to have a long vec of calls participating
+        let set_code_index: CallIndex = Lottery::<T>::call_to_index(
+            &frame_system::Call::<T>::set_code(vec![]).into()
+        )?;
+        let already_called: (u32, Vec<CallIndex>) = (
+            LotteryIndex::get(),
+            vec![
+                set_code_index;
+                T::MaxCalls::get().saturating_sub(1)
+            ],
+        );
+        Participants::<T>::insert(&caller, already_called);
+
+        let call = frame_system::Call::<T>::remark(vec![]);
+    }: _(RawOrigin::Signed(caller), Box::new(call.into()), 10)
+    verify {
+        assert_eq!(TicketsCount::get(), 1);
+        assert_eq!(TicketsWeight::get(), 10);
+    }
+
     set_calls {
         let n in 0 .. T::MaxCalls::get() as u32;
         let calls = vec![frame_system::Call::<T>::remark(vec![]).into(); n as usize];
@@ -125,7 +149,7 @@ benchmarks! {
     }: {
         // Generate `MaxGenerateRandom` numbers for worst case scenario
         for i in 0 .. T::MaxGenerateRandom::get() {
-            Lottery::<T>::generate_random_number(i);
+            Lottery::<T>::generate_random_number(&[], i);
         }
         // Start lottery has block 15 configured for payout
         Lottery::<T>::on_initialize(15u32.into());
@@ -156,7 +180,7 @@ benchmarks! {
     }: {
         // Generate `MaxGenerateRandom` numbers for worst case scenario
         for i in 0 .. T::MaxGenerateRandom::get() {
-            Lottery::<T>::generate_random_number(i);
+            Lottery::<T>::generate_random_number(&[], i);
         }
         // Start lottery has block 15 configured for payout
         Lottery::<T>::on_initialize(15u32.into());
@@ -180,6 +204,7 @@ mod tests {
     fn test_benchmarks() {
         new_test_ext().execute_with(|| {
             assert_ok!(test_benchmark_buy_ticket::<Test>());
+            assert_ok!(test_benchmark_buy_weighted_ticket::<Test>());
             assert_ok!(test_benchmark_set_calls::<Test>());
             assert_ok!(test_benchmark_start_lottery::<Test>());
             assert_ok!(test_benchmark_stop_repeat::<Test>());
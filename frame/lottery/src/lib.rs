@@ -38,6 +38,10 @@
 //! which can contain much more complex logic, such as validation of the
 //! parameters, which this pallet alone cannot do.
 //!
+//! Tickets can also be bought with a weight using `buy_weighted_ticket`, which
+//! costs `weight` times the ticket price and multiplies the odds of winning by
+//! the same factor. A regular ticket has a weight of 1.
+//!
 //! This pallet uses the modulus operator to pick a random winner. It is known
 //! that this might introduce a bias if the random number chosen in a range that
 //! is not perfectly divisible by the total number of participants. The
@@ -64,11 +68,11 @@ use frame_support::{
         Currency, EnsureOrigin, ExistenceRequirement::KeepAlive, Get, Randomness,
         ReservableCurrency,
     },
-    Parameter, RuntimeDebug,
+    IterableStorageMap, Parameter, RuntimeDebug,
 };
 use frame_system::ensure_signed;
 use sp_runtime::{
-    traits::{AccountIdConversion, Saturating, Zero},
+    traits::{AccountIdConversion, Hash, Saturating, Zero},
     DispatchError, ModuleId,
 };
 use sp_std::prelude::*;
@@ -115,6 +119,17 @@ pub trait Config: frame_system::Config {
     /// but also the more likely that the chosen winner is done fairly.
     type MaxGenerateRandom: Get<u32>;
 
+    /// Picks the winning ticket once a lottery pays out.
+    ///
+    /// Set this to the lottery module itself to draw a winner at random, weighted by ticket
+    /// weight.
+    type WinnerSelector: SelectWinner;
+
+    /// Decides where the pot of a lottery that ended without any tickets sold goes.
+    ///
+    /// Set this to `()` to keep the pot for the next lottery.
+    type EmptyLotteryHandler: EmptyLotteryHandler<Self::AccountId, BalanceOf<Self>>;
+
     /// Weight information for extrinsics in this pallet.
     type WeightInfo: WeightInfo;
 }
@@ -159,9 +174,59 @@ impl<T: Config> ValidateCall<T> for Module<T> {
     }
 }
 
+/// Picks the winner of a lottery.
+pub trait SelectWinner {
+    /// Returns the number of the winning ticket among the `ticket_count` tickets sold in the
+    /// lottery.
+    ///
+    /// `cumulative_weight(i)` is the total weight of the tickets up to and including ticket `i`,
+    /// so `cumulative_weight(ticket_count - 1)` is the total weight of the lottery. Each call
+    /// reads from storage, so implementations should only query a few of them.
+    ///
+    /// `random_seed` is taken from the configured `Randomness` in the payout block.
+    fn select_winner(
+        ticket_count: u32,
+        cumulative_weight: &dyn Fn(u32) -> u32,
+        random_seed: &[u8],
+    ) -> u32;
+}
+
+impl<T: Config> SelectWinner for Module<T> {
+    fn select_winner(
+        ticket_count: u32,
+        cumulative_weight: &dyn Fn(u32) -> u32,
+        random_seed: &[u8],
+    ) -> u32 {
+        if ticket_count.is_zero() {
+            return 0;
+        }
+        let total_weight = cumulative_weight(ticket_count - 1);
+        // Draws fresh random numbers as needed to remove the modulo bias.
+        let winning_point = Self::choose_winner(total_weight, random_seed);
+        Self::ticket_at(winning_point, ticket_count, cumulative_weight)
+    }
+}
+
+/// Decides where the pot of a lottery that ended without any tickets sold goes.
+pub trait EmptyLotteryHandler<AccountId, Balance> {
+    /// The account the pot is forwarded to, or `None` to keep it in the lottery account.
+    fn destination() -> Option<AccountId>;
+
+    /// Called once a `pot` has been forwarded to the `destination`.
+    fn on_empty(_pot: Balance) {}
+}
+
+impl<AccountId, Balance> EmptyLotteryHandler<AccountId, Balance> for () {
+    fn destination() -> Option<AccountId> {
+        None
+    }
+}
+
 decl_storage! {
     trait Store for Module<T: Config> as Lottery {
         LotteryIndex: u32;
+        /// The index of the last lottery round whose winner has been drawn.
+        LastDrawnIndex: u32;
         /// The configuration for the current lottery.
         Lottery: Option<LotteryConfig<T::BlockNumber, BalanceOf<T>>>;
         /// Users who have purchased a ticket. (Lottery Index, Tickets Purchased)
@@ -173,12 +238,38 @@ decl_storage! {
         /// May have residual storage from previous lotteries. Use `TicketsCount` to see which ones
         /// are actually valid ticket mappings.
         Tickets: map hasher(twox_64_concat) u32 => Option<T::AccountId>;
+        /// The total weight of all tickets up to and including each ticket.
+        ///
+        /// May have residual storage from previous lotteries, like `Tickets`.
+        TicketsCumulativeWeight: map hasher(twox_64_concat) u32 => u32;
+        /// Total weight of the tickets sold.
+        TicketsWeight: u32;
         /// The calls stored in this pallet to be used in an active lottery if configured
         /// by `Config::ValidateCall`.
         CallIndices: Vec<CallIndex>;
     }
 }
 
+pub mod migrations {
+    use super::*;
+
+    /// Give the tickets of a lottery that was running before ticket weights were introduced a
+    /// weight of 1 each, so that it can still be drawn.
+    ///
+    /// Does nothing if the tickets already have weights or no tickets were sold.
+    pub fn migrate_to_weighted_tickets<T: Config>() -> Weight {
+        let ticket_count = TicketsCount::get();
+        if ticket_count.is_zero() || !TicketsWeight::get().is_zero() {
+            return T::DbWeight::get().reads(2);
+        }
+        for ticket in 0..ticket_count {
+            TicketsCumulativeWeight::insert(ticket, ticket + 1);
+        }
+        TicketsWeight::put(ticket_count);
+        T::DbWeight::get().reads_writes(2, Weight::from(ticket_count) + 1)
+    }
+}
+
 decl_event!(
     pub enum Event<T> where
         <T as frame_system::Config>::AccountId,
@@ -192,6 +283,9 @@ decl_event!(
         Winner(AccountId, Balance),
         /// A ticket has been bought!
         TicketBought(AccountId, CallIndex),
+        /// A lottery ended without any tickets sold and its pot was forwarded.
+        /// \[destination, amount\]
+        LotteryEmpty(AccountId, Balance),
     }
 );
 
@@ -213,6 +307,10 @@ decl_error! {
         TooManyCalls,
         /// Failed to encode calls
         EncodingFailed,
+        /// A ticket must have a weight greater than zero.
+        ZeroTicketWeight,
+        /// The winner of the current lottery round has already been drawn.
+        AlreadyDrawn,
     }
 }
 
@@ -223,6 +321,10 @@ decl_module! {
 
         fn deposit_event() = default;
 
+        fn on_runtime_upgrade() -> Weight {
+            migrations::migrate_to_weighted_tickets::<T>()
+        }
+
         /// Buy a ticket to enter the lottery.
         ///
         /// This extrinsic acts as a passthrough function for `call`. In all
@@ -242,7 +344,26 @@ decl_module! {
             let caller = ensure_signed(origin.clone())?;
             call.clone().dispatch(origin).map_err(|e| e.error)?;
 
-            let _ = Self::do_buy_ticket(&caller, &call);
+            let _ = Self::do_buy_ticket(&caller, &call, 1);
+        }
+
+        /// Buy a ticket with the given `weight` to enter the lottery.
+        ///
+        /// Works like `buy_ticket`, but the ticket costs `weight` times the
+        /// ticket price and its odds of winning are multiplied by `weight`.
+        /// Fails without dispatching `call` if `weight` is zero.
+        ///
+        /// This extrinsic must be called by a signed origin.
+        #[weight =
+            T::WeightInfo::buy_weighted_ticket()
+                .saturating_add(call.get_dispatch_info().weight)
+        ]
+        fn buy_weighted_ticket(origin, call: Box<<T as Config>::Call>, weight: u32) {
+            let caller = ensure_signed(origin.clone())?;
+            ensure!(weight > 0, Error::<T>::ZeroTicketWeight);
+            call.clone().dispatch(origin).map_err(|e| e.error)?;
+
+            let _ = Self::do_buy_ticket(&caller, &call, weight);
         }
 
         /// Set calls in storage which can be used to purchase a lottery ticket.
@@ -320,39 +441,45 @@ decl_module! {
             });
         }
 
+        /// Draw the winner of the current lottery and pay out the pot immediately, without
+        /// waiting for the payout block.
+        ///
+        /// Only a round that is still selling tickets can be drawn, and each round is drawn at
+        /// most once. A repeating lottery continues with a new round starting at the current
+        /// block, any other lottery ends.
+        ///
+        /// This extrinsic must be called by the `ManagerOrigin`.
+        #[weight = T::WeightInfo::on_initialize_end()
+            .max(T::WeightInfo::on_initialize_repeat())
+            .saturating_add(Module::<T>::selection_weight(u32::max_value()))
+        ]
+        fn force_draw(origin) {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            let now = frame_system::Module::<T>::block_number();
+            Lottery::<T>::try_mutate(|lottery| -> DispatchResult {
+                let config = lottery.as_ref().ok_or(Error::<T>::NotConfigured)?;
+                ensure!(
+                    LastDrawnIndex::get() != LotteryIndex::get(),
+                    Error::<T>::AlreadyDrawn
+                );
+                // Once the round has ended its payout is already scheduled.
+                ensure!(
+                    now < config.start.saturating_add(config.length),
+                    Error::<T>::AlreadyEnded
+                );
+                Self::draw(lottery, now);
+                Ok(())
+            })?;
+        }
+
         fn on_initialize(n: T::BlockNumber) -> Weight {
-            Lottery::<T>::mutate(|mut lottery| -> Weight {
-                if let Some(config) = &mut lottery {
+            Lottery::<T>::mutate(|lottery| -> Weight {
+                if let Some(config) = lottery.as_ref() {
                     let payout_block = config.start
                         .saturating_add(config.length)
                         .saturating_add(config.delay);
                     if payout_block <= n {
-                        let (lottery_account, lottery_balance) = Self::pot();
-                        let ticket_count = TicketsCount::get();
-
-                        let winning_number = Self::choose_winner(ticket_count);
-                        let winner = Tickets::<T>::get(winning_number).unwrap_or(lottery_account);
-                        // Not much we can do if this fails...
-                        let _ = T::Currency::transfer(&Self::account_id(), &winner, lottery_balance, KeepAlive);
-
-                        Self::deposit_event(RawEvent::Winner(winner, lottery_balance));
-
-                        TicketsCount::kill();
-
-                        if config.repeat {
-                            // If lottery should repeat, increment index by 1.
-                            LotteryIndex::mutate(|index| *index = index.saturating_add(1));
-                            // Set a new start with the current block.
-                            config.start = n;
-                            return T::WeightInfo::on_initialize_repeat()
-                        } else {
-                            // Else, kill the lottery storage.
-                            *lottery = None;
-                            return T::WeightInfo::on_initialize_end()
-                        }
-                        // We choose not need to kill Participants and Tickets to avoid a large number
-                        // of writes at one time. Instead, data persists between lotteries, but is not used
-                        // if it is not relevant.
+                        return Self::draw(lottery, n)
                     }
                 }
                 return T::DbWeight::get().reads(1)
@@ -370,6 +497,31 @@ impl<T: Config> Module<T> {
         T::ModuleId::get().into_account()
     }
 
+    /// The accounts holding at least one ticket in the current lottery.
+    pub fn participants() -> Vec<T::AccountId> {
+        if !Lottery::<T>::exists() {
+            return Vec::new();
+        }
+        let index = LotteryIndex::get();
+        Participants::<T>::iter()
+            .filter(|(_, (lottery_index, calls))| *lottery_index == index && !calls.is_empty())
+            .map(|(who, _)| who)
+            .collect()
+    }
+
+    /// The number of tickets `who` holds in the current lottery.
+    pub fn ticket_count(who: &T::AccountId) -> u32 {
+        if !Lottery::<T>::exists() {
+            return 0;
+        }
+        let (lottery_index, calls) = Participants::<T>::get(who);
+        if lottery_index == LotteryIndex::get() {
+            calls.len() as u32
+        } else {
+            0
+        }
+    }
+
     /// Return the pot account and amount of money in the pot.
     // The existential deposit is not part of the pot so lottery account never gets deleted.
     fn pot() -> (T::AccountId, BalanceOf<T>) {
@@ -399,8 +551,13 @@ impl<T: Config> Module<T> {
         return Ok((encoded_call[0], encoded_call[1]));
     }
 
-    // Logic for buying a ticket.
-    fn do_buy_ticket(caller: &T::AccountId, call: &<T as Config>::Call) -> DispatchResult {
+    // Logic for buying a ticket with the given weight.
+    fn do_buy_ticket(
+        caller: &T::AccountId,
+        call: &<T as Config>::Call,
+        weight: u32,
+    ) -> DispatchResult {
+        ensure!(!weight.is_zero(), Error::<T>::ZeroTicketWeight);
         // Check the call is valid lottery
         let config = Lottery::<T>::get().ok_or(Error::<T>::NotConfigured)?;
         let block_number = frame_system::Module::<T>::block_number();
@@ -415,6 +572,9 @@ impl<T: Config> Module<T> {
         let call_index = Self::call_to_index(call)?;
         let ticket_count = TicketsCount::get();
         let new_ticket_count = ticket_count.checked_add(1).ok_or(Error::<T>::Overflow)?;
+        let new_tickets_weight = TicketsWeight::get()
+            .checked_add(weight)
+            .ok_or(Error::<T>::Overflow)?;
         // Try to update the participant status
         Participants::<T>::try_mutate(
             &caller,
@@ -432,10 +592,13 @@ impl<T: Config> Module<T> {
                     );
                 }
                 // Check user has enough funds and send it to the Lottery account.
-                T::Currency::transfer(caller, &Self::account_id(), config.price, KeepAlive)?;
+                let price = config.price.saturating_mul(weight.into());
+                T::Currency::transfer(caller, &Self::account_id(), price, KeepAlive)?;
                 // Create a new ticket.
                 TicketsCount::put(new_ticket_count);
                 Tickets::<T>::insert(ticket_count, caller.clone());
+                TicketsWeight::put(new_tickets_weight);
+                TicketsCumulativeWeight::insert(ticket_count, new_tickets_weight);
                 participating_calls.push(call_index);
                 Ok(())
             },
@@ -446,9 +609,88 @@ impl<T: Config> Module<T> {
         Ok(())
     }
 
+    // Pay out the pot of the current lottery at block `n`, then either start its next round or
+    // end it. Does nothing if no lottery is configured.
+    fn draw(
+        lottery: &mut Option<LotteryConfig<T::BlockNumber, BalanceOf<T>>>,
+        n: T::BlockNumber,
+    ) -> Weight {
+        let config = match lottery {
+            Some(config) => config,
+            None => return 0,
+        };
+        let (lottery_account, lottery_balance) = Self::pot();
+        let ticket_count = TicketsCount::get();
+        let mut selection_weight: Weight = 0;
+
+        if ticket_count.is_zero() {
+            // Nobody to pick as a winner, hand the pot over if configured.
+            if let Some(destination) = T::EmptyLotteryHandler::destination() {
+                // Not much we can do if this fails, the pot stays for the next lottery.
+                if T::Currency::transfer(&lottery_account, &destination, lottery_balance, KeepAlive)
+                    .is_ok()
+                {
+                    T::EmptyLotteryHandler::on_empty(lottery_balance);
+                    Self::deposit_event(RawEvent::LotteryEmpty(destination, lottery_balance));
+                }
+            }
+        } else {
+            // Tickets sold before weights were tracked all have the same odds.
+            let weighted = !TicketsWeight::get().is_zero();
+            let cumulative_weight = |ticket: u32| {
+                if weighted {
+                    TicketsCumulativeWeight::get(ticket)
+                } else {
+                    ticket.saturating_add(1)
+                }
+            };
+            let random_seed = T::Randomness::random(&(T::ModuleId::get(), b"winner").encode());
+            let winning_number = T::WinnerSelector::select_winner(
+                ticket_count,
+                &cumulative_weight,
+                random_seed.as_ref(),
+            );
+            let winner = Tickets::<T>::get(winning_number).unwrap_or(lottery_account);
+            selection_weight = Self::selection_weight(ticket_count);
+            // Not much we can do if this fails...
+            let _ = T::Currency::transfer(&Self::account_id(), &winner, lottery_balance, KeepAlive);
+
+            Self::deposit_event(RawEvent::Winner(winner, lottery_balance));
+        }
+
+        TicketsCount::kill();
+        TicketsWeight::kill();
+        LastDrawnIndex::put(LotteryIndex::get());
+
+        // We choose not need to kill Participants, Tickets and TicketsCumulativeWeight to avoid a
+        // large number of writes at one time. Instead, data persists between lotteries, but is not used
+        // if it is not relevant.
+        if config.repeat {
+            // If lottery should repeat, increment index by 1.
+            LotteryIndex::mutate(|index| *index = index.saturating_add(1));
+            // Set a new start with the current block.
+            config.start = n;
+            T::WeightInfo::on_initialize_repeat().saturating_add(selection_weight)
+        } else {
+            // Else, kill the lottery storage.
+            *lottery = None;
+            T::WeightInfo::on_initialize_end().saturating_add(selection_weight)
+        }
+    }
+
+    // The weight of selecting the winner among `ticket_count` tickets: a binary search over the
+    // cumulative weights plus reading the total weight and the winning ticket.
+    fn selection_weight(ticket_count: u32) -> Weight {
+        let search_reads = Weight::from(32 - ticket_count.leading_zeros());
+        T::DbWeight::get().reads(search_reads.saturating_add(2))
+    }
+
     // Randomly choose a winner from among the total number of participants.
-    fn choose_winner(total: u32) -> u32 {
-        let mut random_number = Self::generate_random_number(0);
+    fn choose_winner(total: u32, random_seed: &[u8]) -> u32 {
+        if total.is_zero() {
+            return 0;
+        }
+        let mut random_number = Self::generate_random_number(random_seed, 0);
 
         // Best effort attempt to remove bias from modulus operator.
         for i in 1..T::MaxGenerateRandom::get() {
@@ -456,19 +698,34 @@ impl<T: Config> Module<T> {
                 break;
             }
 
-            random_number = Self::generate_random_number(i);
+            random_number = Self::generate_random_number(random_seed, i);
         }
 
         random_number % total
     }
 
-    // Generate a random number from a given seed.
+    // Find the ticket whose weight range contains `point`, by binary searching the cumulative
+    // weights of the first `ticket_count` tickets.
+    fn ticket_at(point: u32, ticket_count: u32, cumulative_weight: &dyn Fn(u32) -> u32) -> u32 {
+        let (mut low, mut high) = (0, ticket_count);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if cumulative_weight(mid) <= point {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        low
+    }
+
+    // Generate a random number from the random seed of the draw and a given index.
     // Note that there is potential bias introduced by using modulus operator.
-    // You should call this function with different seed values until the random
+    // You should call this function with different index values until the random
     // number lies within `u32::MAX - u32::MAX % n`.
-    fn generate_random_number(seed: u32) -> u32 {
-        let random_seed = T::Randomness::random(&(T::ModuleId::get(), seed).encode());
-        let random_number = <u32>::decode(&mut random_seed.as_ref())
+    fn generate_random_number(random_seed: &[u8], index: u32) -> u32 {
+        let random_hash = T::Hashing::hash_of(&(random_seed, index));
+        let random_number = <u32>::decode(&mut random_hash.as_ref())
             .expect("secure hashes should always be bigger than u32; qed");
         random_number
     }
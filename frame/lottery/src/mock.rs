@@ -28,9 +28,10 @@ use frame_system::EnsureRoot;
 use sp_core::H256;
 use sp_runtime::{
     testing::Header,
-    traits::{BlakeTwo256, IdentityLookup},
+    traits::{BlakeTwo256, Hash, IdentityLookup},
     Perbill,
 };
+use std::cell::RefCell;
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -103,15 +104,86 @@ impl Config for Test {
     type ModuleId = LotteryModuleId;
     type Call = Call;
     type Currency = Balances;
-    type Randomness = TestRandomness;
+    type Randomness = SeededTestRandomness;
     type Event = Event;
     type ManagerOrigin = EnsureRoot<u64>;
     type MaxCalls = MaxCalls;
     type ValidateCall = Lottery;
     type MaxGenerateRandom = MaxGenerateRandom;
+    type WinnerSelector = PickFirstOrRandom;
+    type EmptyLotteryHandler = ForwardToTreasury;
     type WeightInfo = ();
 }
 
+/// The account receiving the pot of lotteries without any tickets sold.
+pub const TREASURY: u64 = 99;
+
+/// Forwards the pot of empty lotteries to [`TREASURY`] and records the forwarded amounts.
+pub struct ForwardToTreasury;
+
+impl EmptyLotteryHandler<u64, u64> for ForwardToTreasury {
+    fn destination() -> Option<u64> {
+        Some(TREASURY)
+    }
+
+    fn on_empty(pot: u64) {
+        FORWARDED_POTS.with(|p| p.borrow_mut().push(pot));
+    }
+}
+
+/// The pots forwarded by [`ForwardToTreasury`] so far.
+pub fn forwarded_pots() -> Vec<u64> {
+    FORWARDED_POTS.with(|p| p.borrow().clone())
+}
+
+thread_local! {
+    static FORWARDED_POTS: RefCell<Vec<u64>> = RefCell::new(Vec::new());
+    static RANDOMNESS_SEED: RefCell<Option<u64>> = RefCell::new(None);
+    static PICK_FIRST: RefCell<bool> = RefCell::new(false);
+}
+
+/// Randomness derived from a seed that tests can change with [`set_randomness_seed`].
+///
+/// Behaves like [`TestRandomness`] as long as no seed is set.
+pub struct SeededTestRandomness;
+
+impl Randomness<H256> for SeededTestRandomness {
+    fn random(subject: &[u8]) -> H256 {
+        match RANDOMNESS_SEED.with(|s| *s.borrow()) {
+            Some(seed) => BlakeTwo256::hash_of(&(seed, subject)),
+            None => TestRandomness::random(subject),
+        }
+    }
+}
+
+/// Set the seed used by [`SeededTestRandomness`].
+pub fn set_randomness_seed(seed: u64) {
+    RANDOMNESS_SEED.with(|s| *s.borrow_mut() = Some(seed));
+}
+
+/// Picks the first ticket once [`set_pick_first`] was called, and defers to the lottery's own
+/// random draw otherwise.
+pub struct PickFirstOrRandom;
+
+impl SelectWinner for PickFirstOrRandom {
+    fn select_winner(
+        ticket_count: u32,
+        cumulative_weight: &dyn Fn(u32) -> u32,
+        random_seed: &[u8],
+    ) -> u32 {
+        if PICK_FIRST.with(|p| *p.borrow()) {
+            0
+        } else {
+            <Lottery as SelectWinner>::select_winner(ticket_count, cumulative_weight, random_seed)
+        }
+    }
+}
+
+/// Make [`PickFirstOrRandom`] always pick the first ticket.
+pub fn set_pick_first() {
+    PICK_FIRST.with(|p| *p.borrow_mut() = true);
+}
+
 pub type SystemCall = frame_system::Call<Test>;
 pub type BalancesCall = pallet_balances::Call<Test>;
 
@@ -20,7 +20,8 @@
 use super::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::{
-    new_test_ext, run_to_block, Balances, BalancesCall, Call, Lottery, Origin, SystemCall, Test,
+    forwarded_pots, new_test_ext, run_to_block, set_pick_first, set_randomness_seed, Balances,
+    BalancesCall, Call, Lottery, Origin, System, SystemCall, Test, TREASURY,
 };
 use pallet_balances::Error as BalancesError;
 use sp_runtime::traits::BadOrigin;
@@ -36,6 +37,37 @@ fn initial_state() {
     });
 }
 
+#[test]
+fn participants_and_ticket_count_work() {
+    new_test_ext().execute_with(|| {
+        let calls = vec![
+            Call::Balances(BalancesCall::transfer(0, 0)),
+            Call::System(SystemCall::remark(vec![])),
+        ];
+        assert_ok!(Lottery::set_calls(Origin::root(), calls));
+        assert_ok!(Lottery::start_lottery(Origin::root(), 1, 10, 5, false));
+        assert!(Lottery::participants().is_empty());
+
+        let transfer = Box::new(Call::Balances(BalancesCall::transfer(2, 1)));
+        let remark = Box::new(Call::System(SystemCall::remark(vec![])));
+        assert_ok!(Lottery::buy_ticket(Origin::signed(1), transfer.clone()));
+        assert_ok!(Lottery::buy_ticket(Origin::signed(1), remark));
+        assert_ok!(Lottery::buy_ticket(Origin::signed(2), transfer));
+
+        let mut participants = Lottery::participants();
+        participants.sort();
+        assert_eq!(participants, vec![1, 2]);
+        assert_eq!(Lottery::ticket_count(&1), 2);
+        assert_eq!(Lottery::ticket_count(&2), 1);
+        assert_eq!(Lottery::ticket_count(&3), 0);
+
+        // Once the lottery is over, nobody holds a ticket anymore.
+        run_to_block(20);
+        assert!(Lottery::participants().is_empty());
+        assert_eq!(Lottery::ticket_count(&1), 0);
+    });
+}
+
 #[test]
 fn basic_end_to_end_works() {
     new_test_ext().execute_with(|| {
@@ -84,8 +116,8 @@ fn basic_end_to_end_works() {
 
         // Go to payout
         run_to_block(25);
-        // User 1 wins
-        assert_eq!(Balances::free_balance(&1), 70 + 40);
+        // User 2 wins, on top of the 20 received from each of the four other callers
+        assert_eq!(Balances::free_balance(&2), 100 - 10 + 4 * 20 + 40);
         // Lottery is reset and restarted
         assert_eq!(TicketsCount::get(), 0);
         assert_eq!(LotteryIndex::get(), 2);
@@ -275,3 +307,267 @@ fn start_lottery_will_create_account() {
         assert_eq!(Balances::total_balance(&Lottery::account_id()), 1);
     });
 }
+
+#[test]
+fn weighted_tickets_change_the_odds() {
+    new_test_ext().execute_with(|| {
+        let calls = vec![
+            Call::System(SystemCall::remark(vec![])),
+            Call::Balances(BalancesCall::transfer(0, 0)),
+        ];
+        assert_ok!(Lottery::set_calls(Origin::root(), calls));
+        assert_ok!(Lottery::start_lottery(Origin::root(), 10, 20, 5, false));
+
+        // Zero weight tickets are rejected before the call is dispatched.
+        let call = Box::new(Call::System(SystemCall::remark(vec![])));
+        assert_noop!(
+            Lottery::buy_weighted_ticket(Origin::signed(1), call.clone(), 0),
+            Error::<Test>::ZeroTicketWeight
+        );
+        assert_eq!(TicketsCount::get(), 0);
+
+        assert_ok!(Lottery::buy_weighted_ticket(
+            Origin::signed(1),
+            call.clone(),
+            9
+        ));
+        assert_eq!(Balances::free_balance(&1), 100 - 90);
+        assert_ok!(Lottery::buy_ticket(Origin::signed(2), call));
+        assert_eq!(Balances::free_balance(&2), 100 - 10);
+        assert_eq!(TicketsCount::get(), 2);
+        assert_eq!(TicketsWeight::get(), 10);
+
+        // Draw with many seeds and check who wins.
+        let draws = 1000;
+        let cumulative_weight = |ticket: u32| TicketsCumulativeWeight::get(ticket);
+        let wins_of_1 = (0..draws)
+            .filter(|seed: &u32| {
+                let winning_number = <Lottery as SelectWinner>::select_winner(
+                    TicketsCount::get(),
+                    &cumulative_weight,
+                    &seed.encode(),
+                );
+                Tickets::<Test>::get(winning_number) == Some(1)
+            })
+            .count();
+        assert!(
+            (850..=950).contains(&wins_of_1),
+            "expected ~90% wins, got {}",
+            wins_of_1
+        );
+    });
+}
+
+#[test]
+fn empty_lottery_pot_is_forwarded() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Lottery::start_lottery(Origin::root(), 10, 20, 5, false));
+        // Someone donates to the pot, but nobody buys a ticket.
+        assert_ok!(Balances::transfer(
+            Origin::signed(1),
+            Lottery::account_id(),
+            50
+        ));
+
+        run_to_block(25);
+        assert!(crate::Lottery::<Test>::get().is_none());
+        assert_eq!(Balances::free_balance(&TREASURY), 50);
+        assert_eq!(Balances::free_balance(&Lottery::account_id()), 1);
+        // the handler was told about the whole pot
+        assert_eq!(forwarded_pots(), vec![50]);
+    });
+}
+
+#[test]
+fn different_seeds_draw_different_winners() {
+    new_test_ext().execute_with(|| {
+        let call = Box::new(Call::Balances(BalancesCall::transfer(5, 1)));
+        assert_ok!(Lottery::set_calls(Origin::root(), vec![*call.clone()]));
+
+        let draw = |seed: u64| {
+            let start = System::block_number();
+            assert_ok!(Lottery::start_lottery(Origin::root(), 10, 10, 5, false));
+            for who in 1..=4 {
+                assert_ok!(Lottery::buy_ticket(Origin::signed(who), call.clone()));
+            }
+            set_randomness_seed(seed);
+            System::reset_events();
+            run_to_block(start + 15);
+            System::events()
+                .into_iter()
+                .find_map(|r| match r.event {
+                    mock::Event::pallet_lottery(RawEvent::Winner(who, _)) => Some(who),
+                    _ => None,
+                })
+                .expect("a winner is drawn")
+        };
+
+        let first = draw(1);
+        let second = draw(4);
+        assert_ne!(first, second);
+    });
+}
+
+#[test]
+fn winner_selector_picks_the_winner() {
+    new_test_ext().execute_with(|| {
+        let call = Box::new(Call::Balances(BalancesCall::transfer(5, 1)));
+        assert_ok!(Lottery::set_calls(Origin::root(), vec![*call.clone()]));
+        assert_ok!(Lottery::start_lottery(Origin::root(), 10, 10, 5, false));
+        for who in &[3, 1, 4] {
+            assert_ok!(Lottery::buy_ticket(Origin::signed(*who), call.clone()));
+        }
+
+        set_pick_first();
+        run_to_block(15);
+
+        // the owner of the first ticket sold wins the pot.
+        assert!(System::events()
+            .into_iter()
+            .any(|r| r.event == mock::Event::pallet_lottery(RawEvent::Winner(3, 30))));
+    });
+}
+
+#[test]
+fn force_draw_works() {
+    new_test_ext().execute_with(|| {
+        // No lottery to draw
+        assert_noop!(
+            Lottery::force_draw(Origin::root()),
+            Error::<Test>::NotConfigured
+        );
+
+        let calls = vec![Call::Balances(BalancesCall::transfer(0, 0))];
+        assert_ok!(Lottery::set_calls(Origin::root(), calls));
+        assert_ok!(Lottery::start_lottery(Origin::root(), 10, 20, 5, false));
+
+        let call = Box::new(Call::Balances(BalancesCall::transfer(2, 20)));
+        assert_ok!(Lottery::buy_ticket(Origin::signed(1), call));
+        assert_eq!(Balances::free_balance(&1), 100 - 20 - 10);
+
+        // Only the manager can force a draw
+        assert_noop!(Lottery::force_draw(Origin::signed(1)), BadOrigin);
+
+        // The only ticket holder wins the pot long before the payout block
+        run_to_block(2);
+        assert_ok!(Lottery::force_draw(Origin::root()));
+        assert_eq!(Balances::free_balance(&1), 70 + 10);
+        assert_eq!(Lottery::pot().1, 0);
+        assert!(crate::Lottery::<Test>::get().is_none());
+        assert_eq!(TicketsCount::get(), 0);
+
+        // The round can't be drawn again
+        assert_noop!(
+            Lottery::force_draw(Origin::root()),
+            Error::<Test>::NotConfigured
+        );
+    });
+}
+
+#[test]
+fn force_draw_draws_each_round_once() {
+    new_test_ext().execute_with(|| {
+        let call = Box::new(Call::Balances(BalancesCall::transfer(5, 1)));
+        assert_ok!(Lottery::set_calls(Origin::root(), vec![*call.clone()]));
+        assert_ok!(Lottery::start_lottery(Origin::root(), 10, 20, 5, true));
+        assert_ok!(Lottery::buy_ticket(Origin::signed(1), call.clone()));
+
+        run_to_block(2);
+        assert_ok!(Lottery::force_draw(Origin::root()));
+        assert_eq!(LastDrawnIndex::get(), 1);
+        // The repeating lottery went on with a new round.
+        assert_eq!(LotteryIndex::get(), 2);
+        assert_eq!(crate::Lottery::<Test>::get().unwrap().start, 2);
+
+        // A round that is still marked as the current one after its draw can't be drawn again.
+        LotteryIndex::put(1);
+        assert_noop!(
+            Lottery::force_draw(Origin::root()),
+            Error::<Test>::AlreadyDrawn
+        );
+    });
+}
+
+#[test]
+fn force_draw_fails_after_round_end() {
+    new_test_ext().execute_with(|| {
+        let call = Box::new(Call::Balances(BalancesCall::transfer(5, 1)));
+        assert_ok!(Lottery::set_calls(Origin::root(), vec![*call.clone()]));
+        assert_ok!(Lottery::start_lottery(Origin::root(), 10, 20, 5, false));
+        assert_ok!(Lottery::buy_ticket(Origin::signed(1), call));
+
+        // The round stopped selling tickets and waits for its payout.
+        run_to_block(20);
+        assert_noop!(
+            Lottery::force_draw(Origin::root()),
+            Error::<Test>::AlreadyEnded
+        );
+
+        // The scheduled payout still happens.
+        run_to_block(25);
+        assert!(crate::Lottery::<Test>::get().is_none());
+        assert!(System::events()
+            .into_iter()
+            .any(|r| r.event == mock::Event::pallet_lottery(RawEvent::Winner(1, 10))));
+    });
+}
+
+#[test]
+fn lottery_without_ticket_weights_is_drawn() {
+    new_test_ext().execute_with(|| {
+        let call = Box::new(Call::Balances(BalancesCall::transfer(5, 1)));
+        assert_ok!(Lottery::set_calls(Origin::root(), vec![*call.clone()]));
+        assert_ok!(Lottery::start_lottery(Origin::root(), 10, 10, 5, false));
+        for who in 1..=3 {
+            assert_ok!(Lottery::buy_ticket(Origin::signed(who), call.clone()));
+        }
+
+        // Tickets sold before ticket weights were tracked.
+        TicketsWeight::kill();
+        for ticket in 0..3 {
+            TicketsCumulativeWeight::remove(ticket);
+        }
+
+        run_to_block(15);
+        assert!(System::events().into_iter().any(|r| matches!(
+            r.event,
+            mock::Event::pallet_lottery(RawEvent::Winner(_, 30))
+        )));
+    });
+}
+
+#[test]
+fn lottery_without_ticket_weights_is_migrated_and_drawn() {
+    new_test_ext().execute_with(|| {
+        let call = Box::new(Call::Balances(BalancesCall::transfer(5, 1)));
+        assert_ok!(Lottery::set_calls(Origin::root(), vec![*call.clone()]));
+        assert_ok!(Lottery::start_lottery(Origin::root(), 10, 10, 5, false));
+        for who in 1..=3 {
+            assert_ok!(Lottery::buy_ticket(Origin::signed(who), call.clone()));
+        }
+
+        // Tickets sold before ticket weights were tracked.
+        TicketsWeight::kill();
+        for ticket in 0..3 {
+            TicketsCumulativeWeight::remove(ticket);
+        }
+
+        // The migration gives every ticket a weight of 1.
+        migrations::migrate_to_weighted_tickets::<Test>();
+        assert_eq!(TicketsWeight::get(), 3);
+        assert_eq!(
+            (0..3).map(TicketsCumulativeWeight::get).collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+
+        // Running it again changes nothing.
+        migrations::migrate_to_weighted_tickets::<Test>();
+        assert_eq!(TicketsWeight::get(), 3);
+
+        run_to_block(15);
+        assert!(System::events().into_iter().any(|r| matches!(
+            r.event,
+            mock::Event::pallet_lottery(RawEvent::Winner(_, 30))
+        )));
+    });
+}
@@ -18,8 +18,8 @@
 //! Autogenerated weights for pallet_lottery
 //!
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 2.0.0
-//! DATE: 2021-01-05, STEPS: [50, ], REPEAT: 20, LOW RANGE: [], HIGH RANGE: []
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 128
+//! DATE: 2026-10-17, STEPS: [50, ], REPEAT: 20, LOW RANGE: [], HIGH RANGE: []
+//! EXECUTION: Some(Native), WASM-EXECUTION: Interpreted, CHAIN: Some("dev"), DB CACHE: 128
 
 // Executed Command:
 // target/release/substrate
@@ -29,8 +29,7 @@
 // --repeat=20
 // --pallet=pallet_lottery
 // --extrinsic=*
-// --execution=wasm
-// --wasm-execution=compiled
+// --execution=native
 // --heap-pages=4096
 // --output=./frame/lottery/src/weights.rs
 // --template=./.maintain/frame-weight-template.hbs
@@ -47,6 +46,7 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for pallet_lottery.
 pub trait WeightInfo {
     fn buy_ticket() -> Weight;
+    fn buy_weighted_ticket() -> Weight;
     fn set_calls(n: u32) -> Weight;
     fn start_lottery() -> Weight;
     fn stop_repeat() -> Weight;
@@ -58,69 +58,79 @@ pub trait WeightInfo {
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     fn buy_ticket() -> Weight {
-        (97_799_000 as Weight)
-            .saturating_add(T::DbWeight::get().reads(6 as Weight))
-            .saturating_add(T::DbWeight::get().writes(4 as Weight))
+        (59_526_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(7 as Weight))
+            .saturating_add(T::DbWeight::get().writes(6 as Weight))
+    }
+    fn buy_weighted_ticket() -> Weight {
+        (54_051_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(7 as Weight))
+            .saturating_add(T::DbWeight::get().writes(6 as Weight))
     }
     fn set_calls(n: u32) -> Weight {
-        (20_932_000 as Weight)
-            // Standard Error: 9_000
-            .saturating_add((513_000 as Weight).saturating_mul(n as Weight))
+        (11_411_000 as Weight)
+            // Standard Error: 4_000
+            .saturating_add((108_000 as Weight).saturating_mul(n as Weight))
             .saturating_add(T::DbWeight::get().writes(1 as Weight))
     }
     fn start_lottery() -> Weight {
-        (77_600_000 as Weight)
+        (35_097_000 as Weight)
             .saturating_add(T::DbWeight::get().reads(3 as Weight))
             .saturating_add(T::DbWeight::get().writes(3 as Weight))
     }
     fn stop_repeat() -> Weight {
-        (10_707_000 as Weight)
+        (5_154_000 as Weight)
             .saturating_add(T::DbWeight::get().reads(1 as Weight))
             .saturating_add(T::DbWeight::get().writes(1 as Weight))
     }
     fn on_initialize_end() -> Weight {
-        (162_126_000 as Weight)
-            .saturating_add(T::DbWeight::get().reads(6 as Weight))
-            .saturating_add(T::DbWeight::get().writes(4 as Weight))
+        (69_644_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(9 as Weight))
+            .saturating_add(T::DbWeight::get().writes(6 as Weight))
     }
     fn on_initialize_repeat() -> Weight {
-        (169_310_000 as Weight)
-            .saturating_add(T::DbWeight::get().reads(7 as Weight))
-            .saturating_add(T::DbWeight::get().writes(5 as Weight))
+        (70_309_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(9 as Weight))
+            .saturating_add(T::DbWeight::get().writes(7 as Weight))
     }
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
     fn buy_ticket() -> Weight {
-        (97_799_000 as Weight)
-            .saturating_add(RocksDbWeight::get().reads(6 as Weight))
-            .saturating_add(RocksDbWeight::get().writes(4 as Weight))
+        (59_526_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(7 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(6 as Weight))
+    }
+    fn buy_weighted_ticket() -> Weight {
+        (54_051_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(7 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(6 as Weight))
     }
     fn set_calls(n: u32) -> Weight {
-        (20_932_000 as Weight)
-            // Standard Error: 9_000
-            .saturating_add((513_000 as Weight).saturating_mul(n as Weight))
+        (11_411_000 as Weight)
+            // Standard Error: 4_000
+            .saturating_add((108_000 as Weight).saturating_mul(n as Weight))
             .saturating_add(RocksDbWeight::get().writes(1 as Weight))
     }
     fn start_lottery() -> Weight {
-        (77_600_000 as Weight)
+        (35_097_000 as Weight)
             .saturating_add(RocksDbWeight::get().reads(3 as Weight))
             .saturating_add(RocksDbWeight::get().writes(3 as Weight))
     }
     fn stop_repeat() -> Weight {
-        (10_707_000 as Weight)
+        (5_154_000 as Weight)
             .saturating_add(RocksDbWeight::get().reads(1 as Weight))
             .saturating_add(RocksDbWeight::get().writes(1 as Weight))
     }
     fn on_initialize_end() -> Weight {
-        (162_126_000 as Weight)
-            .saturating_add(RocksDbWeight::get().reads(6 as Weight))
-            .saturating_add(RocksDbWeight::get().writes(4 as Weight))
+        (69_644_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(9 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(6 as Weight))
     }
     fn on_initialize_repeat() -> Weight {
-        (169_310_000 as Weight)
-            .saturating_add(RocksDbWeight::get().reads(7 as Weight))
-            .saturating_add(RocksDbWeight::get().writes(5 as Weight))
+        (70_309_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(9 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(7 as Weight))
     }
 }
@@ -25,7 +25,7 @@ use sp_std::prelude::*;
 use sp_std::vec;
 
 use frame_benchmarking::{account, benchmarks};
-use frame_support::traits::{Currency, OnInitialize, ValidatorSet, ValidatorSetWithIdentification};
+use frame_support::traits::{Currency, Get, OnInitialize, ValidatorSet, ValidatorSetWithIdentification};
 use frame_system::{Config as SystemConfig, Module as System, RawOrigin};
 
 use sp_runtime::{
@@ -339,6 +339,12 @@ benchmarks! {
                 .chain(slash_events.into_iter().map(Into::into))
                 .chain(reward_events)
                 .chain(slash_rest.into_iter().map(Into::into))
+                .chain(std::iter::once(<T as OffencesConfig>::Event::from(
+                    pallet_offences::Event::OffencesProcessed(
+                        Offences::<T>::last_offence_batch_weight(),
+                        <T as OffencesConfig>::WeightSoftLimit::get(),
+                    )
+                ).into()))
                 .chain(std::iter::once(<T as OffencesConfig>::Event::from(
                     pallet_offences::Event::Offence(
                         UnresponsivenessOffence::<T>::ID,
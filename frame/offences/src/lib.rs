@@ -27,7 +27,8 @@ mod tests;
 
 use codec::{Decode, Encode};
 use frame_support::{
-    debug, decl_event, decl_module, decl_storage, traits::Get, weights::Weight, Parameter,
+    debug, decl_event, decl_module, decl_storage, storage::IterableStorageDoubleMap, traits::Get,
+    weights::Weight, Parameter,
 };
 use sp_runtime::{
     traits::{Hash, Zero},
@@ -74,6 +75,16 @@ impl WeightInfo for () {
     }
 }
 
+/// A hook called with the reporters of every offence report that is not a duplicate.
+pub trait OnOffenceReported<Reporter> {
+    /// The offence reported by `reporters` was stored.
+    fn on_offence_reported(reporters: &[Reporter]);
+}
+
+impl<Reporter> OnOffenceReported<Reporter> for () {
+    fn on_offence_reported(_reporters: &[Reporter]) {}
+}
+
 /// Offences trait
 pub trait Config: frame_system::Config {
     /// The overarching event type.
@@ -82,6 +93,8 @@ pub trait Config: frame_system::Config {
     type IdentificationTuple: Parameter + Ord;
     /// A handler called for every offence report.
     type OnOffenceHandler: OnOffenceHandler<Self::AccountId, Self::IdentificationTuple, Weight>;
+    /// A hook called with the reporters of every stored offence report, e.g. to reward them.
+    type OnReport: OnOffenceReported<Self::AccountId>;
     /// The a soft limit on maximum weight that may be consumed while dispatching deferred offences in
     /// `on_initialize`.
     /// Note it's going to be exceeded before we stop adding to it, so it has to be set conservatively.
@@ -111,6 +124,13 @@ decl_storage! {
         /// Note that the actual type of this mapping is `Vec<u8>`, this is because values of
         /// different types are not supported at the moment so we are doing the manual serialization.
         ReportsByKindIndex: map hasher(twox_64_concat) Kind => Vec<u8>; // (O::TimeSlot, ReportIdOf<T>)
+
+        /// The weight consumed by the most recent successful dispatch of offences to the
+        /// `OnOffenceHandler`, either of a single report or of a batch of deferred offences.
+        LastOffenceBatchWeight get(fn last_offence_batch_weight): Weight;
+
+        /// The number of reports of each kind that were not rejected as duplicates.
+        OffenceCountByKind get(fn offence_count): map hasher(twox_64_concat) Kind => u32;
     }
 }
 
@@ -121,6 +141,10 @@ decl_event!(
         /// element indicates of the offence was applied (true) or queued (false)
         /// \[kind, timeslot, applied\].
         Offence(Kind, OpaqueTimeSlot, bool),
+        /// A batch of offences was dispatched, either immediately on report or deferred in
+        /// `on_initialize`, consuming the given weight against the `WeightSoftLimit`.
+        /// \[weight_used, soft_limit\]
+        OffencesProcessed(Weight, Weight),
     }
 );
 
@@ -136,6 +160,7 @@ decl_module! {
 
             let limit = T::WeightSoftLimit::get();
             let mut consumed = Weight::zero();
+            let mut processed = false;
 
             <DeferredOffences<T>>::mutate(|deferred| {
                 deferred.retain(|(offences, perbill, session)| {
@@ -147,6 +172,7 @@ decl_module! {
                         match T::OnOffenceHandler::on_offence(&offences, &perbill, *session) {
                             Ok(weight) => {
                                 consumed += weight;
+                                processed = true;
                                 false
                             },
                             Err(_) => {
@@ -162,6 +188,13 @@ decl_module! {
                 })
             });
 
+            if processed {
+                LastOffenceBatchWeight::put(consumed);
+                Self::deposit_event(Event::OffencesProcessed(consumed, limit));
+                // the write of `LastOffenceBatchWeight`.
+                consumed = consumed.saturating_add(T::DbWeight::get().writes(1));
+            }
+
             consumed
         }
     }
@@ -181,12 +214,14 @@ where
         // in unique reports.
         let TriageOutcome {
             concurrent_offenders,
-        } = match Self::triage_offence_report::<O>(reporters, &time_slot, offenders) {
+        } = match Self::triage_offence_report::<O>(reporters.clone(), &time_slot, offenders) {
             Some(triage) => triage,
             // The report contained only duplicates, so there is no need to slash again.
             None => return Err(OffenceError::DuplicateReport),
         };
 
+        OffenceCountByKind::mutate(&O::ID, |count| *count = count.saturating_add(1));
+
         let offenders_count = concurrent_offenders.len() as u32;
 
         // The amount new offenders are slashed
@@ -202,6 +237,8 @@ where
             offence.session_index(),
         );
 
+        T::OnReport::on_offence_reported(&reporters);
+
         // Deposit the event.
         Self::deposit_event(Event::Offence(O::ID, time_slot.encode(), applied));
 
@@ -209,6 +246,20 @@ where
     }
 
     fn is_known_offence(offenders: &[T::IdentificationTuple], time_slot: &O::TimeSlot) -> bool {
+        Self::already_reported::<O>(offenders, time_slot)
+    }
+}
+
+impl<T: Config> Module<T> {
+    /// Returns `true` if all `offenders` have already been reported for an offence of kind `O`
+    /// at the given `time_slot`.
+    ///
+    /// This allows reporters to skip building an offence (and its proofs) that would be rejected
+    /// as a duplicate.
+    pub fn already_reported<O: Offence<T::IdentificationTuple>>(
+        offenders: &[T::IdentificationTuple],
+        time_slot: &O::TimeSlot,
+    ) -> bool {
         let any_unknown = offenders.iter().any(|offender| {
             let report_id = Self::report_id::<O>(time_slot, offender);
             !<Reports<T>>::contains_key(&report_id)
@@ -216,9 +267,7 @@ where
 
         !any_unknown
     }
-}
 
-impl<T: Config> Module<T> {
     /// Tries (without checking) to report an offence. Stores them in [`DeferredOffences`] in case
     /// it fails. Returns false in case it has to store the offence.
     fn report_or_store_offence(
@@ -228,7 +277,11 @@ impl<T: Config> Module<T> {
     ) -> bool {
         match T::OnOffenceHandler::on_offence(&concurrent_offenders, &slash_perbill, session_index)
         {
-            Ok(_) => true,
+            Ok(weight) => {
+                LastOffenceBatchWeight::put(weight);
+                Self::deposit_event(Event::OffencesProcessed(weight, T::WeightSoftLimit::get()));
+                true
+            }
             Err(_) => {
                 <DeferredOffences<T>>::mutate(|d| {
                     d.push((
@@ -297,6 +350,35 @@ impl<T: Config> Module<T> {
         }
     }
 
+    /// Returns all reports of the given `kind` that happened at a time slot within the inclusive
+    /// range `from..=to`, ordered by time slot.
+    ///
+    /// `TimeSlot` must be the time slot type of the offences of the given `kind`. Time slots that
+    /// fail to decode as `TimeSlot` are skipped.
+    pub fn reports_in_range<TimeSlot: Decode + Ord>(
+        kind: Kind,
+        from: TimeSlot,
+        to: TimeSlot,
+    ) -> Vec<OffenceDetails<T::AccountId, T::IdentificationTuple>> {
+        let mut reports_by_time_slot = <ConcurrentReportsIndex<T>>::iter_prefix(&kind)
+            .filter_map(|(opaque_time_slot, report_ids)| {
+                let time_slot = TimeSlot::decode(&mut &opaque_time_slot[..]).ok()?;
+                if time_slot >= from && time_slot <= to {
+                    Some((time_slot, report_ids))
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+        reports_by_time_slot.sort_by(|a, b| a.0.cmp(&b.0));
+
+        reports_by_time_slot
+            .into_iter()
+            .flat_map(|(_, report_ids)| report_ids)
+            .filter_map(|report_id| <Reports<T>>::get(report_id))
+            .collect()
+    }
+
     #[cfg(feature = "runtime-benchmarks")]
     pub fn set_deferred_offences(offences: Vec<DeferredOffenceOf<T>>) {
         <DeferredOffences<T>>::put(offences);
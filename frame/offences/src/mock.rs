@@ -44,22 +44,38 @@ pub struct OnOffenceHandler;
 
 thread_local! {
     pub static ON_OFFENCE_PERBILL: RefCell<Vec<Perbill>> = RefCell::new(Default::default());
+    pub static ON_OFFENCE_OFFENDER_PERBILL: RefCell<Vec<(u64, Perbill)>> =
+        RefCell::new(Default::default());
     pub static CAN_REPORT: RefCell<bool> = RefCell::new(true);
     pub static OFFENCE_WEIGHT: RefCell<Weight> = RefCell::new(Default::default());
+    pub static REPORTED_BY: RefCell<Vec<Vec<u64>>> = RefCell::new(Default::default());
 }
 
-impl<Reporter, Offender> offence::OnOffenceHandler<Reporter, Offender, Weight>
-    for OnOffenceHandler
-{
+pub struct OnReport;
+
+impl offences::OnOffenceReported<u64> for OnReport {
+    fn on_offence_reported(reporters: &[u64]) {
+        REPORTED_BY.with(|r| r.borrow_mut().push(reporters.to_vec()));
+    }
+}
+
+impl<Reporter> offence::OnOffenceHandler<Reporter, u64, Weight> for OnOffenceHandler {
     fn on_offence(
-        _offenders: &[OffenceDetails<Reporter, Offender>],
+        offenders: &[OffenceDetails<Reporter, u64>],
         slash_fraction: &[Perbill],
         _offence_session: SessionIndex,
     ) -> Result<Weight, ()> {
-        if <Self as offence::OnOffenceHandler<Reporter, Offender, Weight>>::can_report() {
+        if <Self as offence::OnOffenceHandler<Reporter, u64, Weight>>::can_report() {
             ON_OFFENCE_PERBILL.with(|f| {
                 *f.borrow_mut() = slash_fraction.to_vec();
             });
+            ON_OFFENCE_OFFENDER_PERBILL.with(|f| {
+                *f.borrow_mut() = offenders
+                    .iter()
+                    .map(|details| details.offender)
+                    .zip(slash_fraction.iter().cloned())
+                    .collect();
+            });
 
             Ok(OFFENCE_WEIGHT.with(|w| *w.borrow()))
         } else {
@@ -80,6 +96,14 @@ pub fn with_on_offence_fractions<R, F: FnOnce(&mut Vec<Perbill>) -> R>(f: F) ->
     ON_OFFENCE_PERBILL.with(|fractions| f(&mut *fractions.borrow_mut()))
 }
 
+pub fn with_offender_fractions<R, F: FnOnce(&mut Vec<(u64, Perbill)>) -> R>(f: F) -> R {
+    ON_OFFENCE_OFFENDER_PERBILL.with(|fractions| f(&mut *fractions.borrow_mut()))
+}
+
+pub fn reported_by() -> Vec<Vec<u64>> {
+    REPORTED_BY.with(|r| r.borrow().clone())
+}
+
 pub fn set_offence_weight(new: Weight) {
     OFFENCE_WEIGHT.with(|w| *w.borrow_mut() = new);
 }
@@ -137,6 +161,7 @@ impl Config for Runtime {
     type Event = Event;
     type IdentificationTuple = u64;
     type OnOffenceHandler = OnOffenceHandler;
+    type OnReport = OnReport;
     type WeightSoftLimit = OffencesWeightSoftLimit;
 }
 
@@ -21,8 +21,8 @@
 
 use super::*;
 use crate::mock::{
-    new_test_ext, offence_reports, set_can_report, set_offence_weight, with_on_offence_fractions,
-    Event, Offence, Offences, System, KIND,
+    new_test_ext, offence_reports, reported_by, set_can_report, set_offence_weight,
+    with_offender_fractions, with_on_offence_fractions, Event, Offence, Offences, System, KIND,
 };
 use frame_support::traits::OnInitialize;
 use frame_system::{EventRecord, Phase};
@@ -51,6 +51,59 @@ fn should_report_an_authority_and_trigger_on_offence() {
     });
 }
 
+#[test]
+fn reports_in_range_returns_reports_within_the_range() {
+    new_test_ext().execute_with(|| {
+        // given
+        for (time_slot, offender) in vec![(10u128, 5), (20, 6), (30, 7)] {
+            let offence = Offence {
+                validator_set_count: 5,
+                time_slot,
+                offenders: vec![offender],
+            };
+            Offences::report_offence(vec![], offence).unwrap();
+        }
+
+        // when
+        let reports = Offences::reports_in_range(KIND, 15u128, 25u128);
+
+        // then
+        assert_eq!(
+            reports,
+            vec![OffenceDetails {
+                offender: 6,
+                reporters: vec![],
+            }]
+        );
+        assert_eq!(Offences::reports_in_range(KIND, 10u128, 30u128).len(), 3);
+    });
+}
+
+#[test]
+fn already_reported_works() {
+    new_test_ext().execute_with(|| {
+        // given
+        let time_slot = 42;
+        let already_reported = |offenders: &[u64], time_slot: u128| {
+            Offences::already_reported::<Offence<u64>>(offenders, &time_slot)
+        };
+        let offence = Offence {
+            validator_set_count: 5,
+            time_slot,
+            offenders: vec![5],
+        };
+        assert!(!already_reported(&[5], time_slot));
+
+        // when
+        Offences::report_offence(vec![], offence).unwrap();
+
+        // then
+        assert!(already_reported(&[5], time_slot));
+        assert!(!already_reported(&[5, 6], time_slot));
+        assert!(!already_reported(&[5], time_slot + 1));
+    });
+}
+
 #[test]
 fn should_not_report_the_same_authority_twice_in_the_same_slot() {
     new_test_ext().execute_with(|| {
@@ -113,6 +166,70 @@ fn should_report_in_different_time_slot() {
     });
 }
 
+#[test]
+fn should_notify_reporters_of_stored_offences() {
+    new_test_ext().execute_with(|| {
+        // given
+        let offence = Offence {
+            validator_set_count: 5,
+            time_slot: 42,
+            offenders: vec![5],
+        };
+
+        // when
+        Offences::report_offence(vec![1, 2], offence.clone()).unwrap();
+        // a duplicate report is rejected and not passed to the hook.
+        assert!(Offences::report_offence(vec![3], offence).is_err());
+
+        // then
+        assert_eq!(reported_by(), vec![vec![1, 2]]);
+    });
+}
+
+#[test]
+fn should_record_fractions_per_offender() {
+    new_test_ext().execute_with(|| {
+        // given
+        let time_slot = 42;
+        Offences::report_offence(
+            vec![],
+            Offence {
+                validator_set_count: 5,
+                time_slot,
+                offenders: vec![5],
+            },
+        )
+        .unwrap();
+        with_offender_fractions(|f| {
+            assert_eq!(f.clone(), vec![(5, Perbill::from_percent(25))]);
+        });
+
+        // when
+        // a different offender is reported in the same time slot
+        Offences::report_offence(
+            vec![],
+            Offence {
+                validator_set_count: 5,
+                time_slot,
+                offenders: vec![4],
+            },
+        )
+        .unwrap();
+
+        // then
+        // both concurrent offenders are slashed for two offenders in the set.
+        with_offender_fractions(|f| {
+            assert_eq!(
+                f.clone(),
+                vec![
+                    (5, Perbill::from_percent(45)),
+                    (4, Perbill::from_percent(45)),
+                ]
+            );
+        });
+    });
+}
+
 #[test]
 fn should_deposit_event() {
     new_test_ext().execute_with(|| {
@@ -130,13 +247,21 @@ fn should_deposit_event() {
         Offences::report_offence(vec![], offence).unwrap();
 
         // then
+        let soft_limit = <mock::Runtime as Config>::WeightSoftLimit::get();
         assert_eq!(
             System::events(),
-            vec![EventRecord {
-                phase: Phase::Initialization,
-                event: Event::offences(crate::Event::Offence(KIND, time_slot.encode(), true)),
-                topics: vec![],
-            }]
+            vec![
+                EventRecord {
+                    phase: Phase::Initialization,
+                    event: Event::offences(crate::Event::OffencesProcessed(0, soft_limit)),
+                    topics: vec![],
+                },
+                EventRecord {
+                    phase: Phase::Initialization,
+                    event: Event::offences(crate::Event::Offence(KIND, time_slot.encode(), true)),
+                    topics: vec![],
+                },
+            ]
         );
     });
 }
@@ -168,13 +293,21 @@ fn doesnt_deposit_event_for_dups() {
 
         // then
         // there is only one event.
+        let soft_limit = <mock::Runtime as Config>::WeightSoftLimit::get();
         assert_eq!(
             System::events(),
-            vec![EventRecord {
-                phase: Phase::Initialization,
-                event: Event::offences(crate::Event::Offence(KIND, time_slot.encode(), true)),
-                topics: vec![],
-            }]
+            vec![
+                EventRecord {
+                    phase: Phase::Initialization,
+                    event: Event::offences(crate::Event::OffencesProcessed(0, soft_limit)),
+                    topics: vec![],
+                },
+                EventRecord {
+                    phase: Phase::Initialization,
+                    event: Event::offences(crate::Event::Offence(KIND, time_slot.encode(), true)),
+                    topics: vec![],
+                },
+            ]
         );
     });
 }
@@ -392,3 +525,96 @@ fn weight_soft_limit_is_used() {
         assert_eq!(Offences::deferred_offences().len(), 0);
     })
 }
+
+#[test]
+fn last_offence_batch_weight_is_recorded() {
+    new_test_ext().execute_with(|| {
+        set_offence_weight(1_000);
+        assert_eq!(Offences::last_offence_batch_weight(), 0);
+
+        // reported right away.
+        let offence = Offence {
+            validator_set_count: 5,
+            time_slot: 42,
+            offenders: vec![5],
+        };
+        Offences::report_offence(vec![], offence).unwrap();
+        let soft_limit = <mock::Runtime as Config>::WeightSoftLimit::get();
+        assert_eq!(Offences::last_offence_batch_weight(), 1_000);
+        assert!(System::events().contains(&EventRecord {
+            phase: Phase::Initialization,
+            event: Event::offences(crate::Event::OffencesProcessed(1_000, soft_limit)),
+            topics: vec![],
+        }));
+
+        // two deferred offences are dispatched as one batch.
+        set_can_report(false);
+        for time_slot in vec![62, 72] {
+            let offence = Offence {
+                validator_set_count: 5,
+                time_slot,
+                offenders: vec![5],
+            };
+            Offences::report_offence(vec![], offence).unwrap();
+        }
+        set_can_report(true);
+        System::reset_events();
+
+        let write_weight = <mock::Runtime as frame_system::Config>::DbWeight::get().writes(1);
+        assert_eq!(Offences::on_initialize(2), 2_000 + write_weight);
+        assert_eq!(Offences::last_offence_batch_weight(), 2_000);
+        assert_eq!(
+            System::events(),
+            vec![EventRecord {
+                phase: Phase::Initialization,
+                event: Event::offences(crate::Event::OffencesProcessed(2_000, soft_limit)),
+                topics: vec![],
+            }]
+        );
+    });
+}
+
+#[test]
+fn offences_are_counted_by_kind() {
+    /// Same as the mock offence, but of a different kind.
+    struct OtherOffence(Offence<u64>);
+
+    impl sp_staking::offence::Offence<u64> for OtherOffence {
+        const ID: Kind = *b"test_report_5678";
+        type TimeSlot = u128;
+
+        fn offenders(&self) -> Vec<u64> {
+            self.0.offenders()
+        }
+        fn validator_set_count(&self) -> u32 {
+            self.0.validator_set_count()
+        }
+        fn time_slot(&self) -> u128 {
+            self.0.time_slot()
+        }
+        fn session_index(&self) -> SessionIndex {
+            self.0.session_index()
+        }
+        fn slash_fraction(offenders_count: u32, validator_set_count: u32) -> Perbill {
+            Offence::<u64>::slash_fraction(offenders_count, validator_set_count)
+        }
+    }
+
+    new_test_ext().execute_with(|| {
+        let offence = |time_slot| Offence {
+            validator_set_count: 5,
+            time_slot,
+            offenders: vec![5],
+        };
+        let other_kind = <OtherOffence as sp_staking::offence::Offence<u64>>::ID;
+
+        Offences::report_offence(vec![], offence(42)).unwrap();
+        Offences::report_offence(vec![], offence(43)).unwrap();
+        // duplicates are not counted.
+        assert!(Offences::report_offence(vec![], offence(43)).is_err());
+        Offences::report_offence(vec![], OtherOffence(offence(42))).unwrap();
+
+        assert_eq!(Offences::offence_count(KIND), 2);
+        assert_eq!(Offences::offence_count(other_kind), 1);
+    });
+}
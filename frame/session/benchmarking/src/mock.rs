@@ -82,11 +82,15 @@ impl pallet_balances::Config for Test {
 
 parameter_types! {
     pub const MinimumPeriod: u64 = 5;
+    pub const MedianWindow: u32 = 5;
+    pub const MaxPastDriftMillis: u64 = u64::max_value();
 }
 impl pallet_timestamp::Config for Test {
     type Moment = u64;
     type OnTimestampSet = ();
     type MinimumPeriod = MinimumPeriod;
+    type MedianWindow = MedianWindow;
+    type MaxPastDriftMillis = MaxPastDriftMillis;
     type WeightInfo = ();
 }
 impl pallet_session::historical::Config for Test {
@@ -17,7 +17,7 @@
 
 use crate::hash::{ReversibleStorageHasher, StorageHasher, Twox128};
 use crate::{
-    storage::{self, unhashed, PrefixIterator, StorageAppend},
+    storage::{self, unhashed, KeyPrefixIterator, PrefixIterator, StorageAppend},
     Never,
 };
 use codec::{Decode, Encode, EncodeLike, FullCodec, FullEncode};
@@ -203,6 +203,25 @@ where
         }
     }
 
+    fn relocate<XKArg1, XKArg2, YKArg1, YKArg2>(
+        from_k1: XKArg1,
+        from_k2: XKArg2,
+        to_k1: YKArg1,
+        to_k2: YKArg2,
+    ) where
+        XKArg1: EncodeLike<K1>,
+        XKArg2: EncodeLike<K2>,
+        YKArg1: EncodeLike<K1>,
+        YKArg2: EncodeLike<K2>,
+    {
+        let from_key = Self::storage_double_map_final_key(from_k1, from_k2);
+
+        if let Some(val) = unhashed::take::<V>(&from_key) {
+            let to_key = Self::storage_double_map_final_key(to_k1, to_k2);
+            unhashed::put(&to_key, &val);
+        }
+    }
+
     fn insert<KArg1, KArg2, VArg>(k1: KArg1, k2: KArg2, val: VArg)
     where
         KArg1: EncodeLike<K1>,
@@ -236,6 +255,7 @@ where
             prefix: prefix.clone(),
             previous_key: prefix,
             drain: false,
+            limit: None,
             closure: |_raw_key, mut raw_value| V::decode(&mut raw_value),
         }
     }
@@ -364,6 +384,7 @@ where
             prefix: prefix.clone(),
             previous_key: prefix,
             drain: false,
+            limit: None,
             closure: |raw_key_without_prefix, mut raw_value| {
                 let mut key_material = G::Hasher2::reverse(raw_key_without_prefix);
                 Ok((K2::decode(&mut key_material)?, V::decode(&mut raw_value)?))
@@ -371,18 +392,54 @@ where
         }
     }
 
+    fn iter_key_prefix(k1: impl EncodeLike<K1>) -> KeyPrefixIterator<K2> {
+        let prefix = G::storage_double_map_final_key1(k1);
+        KeyPrefixIterator {
+            prefix: prefix.clone(),
+            previous_key: prefix,
+            closure: |raw_key_without_prefix| {
+                let mut key_material = G::Hasher2::reverse(raw_key_without_prefix);
+                K2::decode(&mut key_material)
+            },
+        }
+    }
+
     fn drain_prefix(k1: impl EncodeLike<K1>) -> Self::PrefixIterator {
         let mut iterator = Self::iter_prefix(k1);
         iterator.drain = true;
         iterator
     }
 
+    fn drain_prefix_limited(k1: impl EncodeLike<K1>, limit: u32) -> (Self::PrefixIterator, u32) {
+        let mut iterator = Self::drain_prefix(k1);
+        iterator.limit = Some(limit);
+
+        // The iterator visits keys in order, so every key after the first `limit` ones is left
+        // in the map. Count them without draining.
+        let mut visited = 0u32;
+        let mut remaining = 0u32;
+        let mut previous_key = iterator.previous_key.clone();
+        while let Some(next) =
+            sp_io::storage::next_key(&previous_key).filter(|n| n.starts_with(&iterator.prefix))
+        {
+            if visited < limit {
+                visited += 1;
+            } else {
+                remaining = remaining.saturating_add(1);
+            }
+            previous_key = next;
+        }
+
+        (iterator, remaining)
+    }
+
     fn iter() -> Self::Iterator {
         let prefix = G::prefix_hash();
         Self::Iterator {
             prefix: prefix.clone(),
             previous_key: prefix,
             drain: false,
+            limit: None,
             closure: |raw_key_without_prefix, mut raw_value| {
                 let mut k1_k2_material = G::Hasher1::reverse(raw_key_without_prefix);
                 let k1 = K1::decode(&mut k1_k2_material)?;
@@ -485,6 +542,62 @@ mod test_iterators {
         prefix
     }
 
+    #[test]
+    fn drain_prefix_limited_works() {
+        sp_io::TestExternalities::default().execute_with(|| {
+            for i in 0..6 {
+                DoubleMap::insert(1, i as u32, i as u64);
+            }
+            DoubleMap::insert(2, 0, 0);
+
+            let (drained, remaining) = DoubleMap::drain_prefix_limited(1, 4);
+            assert_eq!(remaining, 2);
+            assert_eq!(drained.count(), 4);
+            assert_eq!(DoubleMap::iter_prefix(1).count(), 2);
+
+            let (drained, remaining) = DoubleMap::drain_prefix_limited(1, 4);
+            assert_eq!(remaining, 0);
+            assert_eq!(drained.count(), 2);
+            assert_eq!(DoubleMap::iter_prefix(1).count(), 0);
+            assert_eq!(DoubleMap::iter_prefix(2).count(), 1);
+        });
+    }
+
+    #[test]
+    fn iter_key_prefix_works() {
+        sp_io::TestExternalities::default().execute_with(|| {
+            for i in 0..3 {
+                DoubleMap::insert(1, i as u32, i as u64);
+            }
+            DoubleMap::insert(2, 7, 7);
+            // a value that can't be decoded as `u64`.
+            unhashed::put_raw(&DoubleMap::storage_double_map_final_key(1, 3), &[1]);
+
+            let mut keys = DoubleMap::iter_key_prefix(1).collect::<Vec<_>>();
+            keys.sort();
+            assert_eq!(keys, vec![0, 1, 2, 3]);
+            // the undecodable value is skipped when values are decoded.
+            assert_eq!(DoubleMap::iter_prefix(1).count(), 3);
+        });
+    }
+
+    #[test]
+    fn relocate_works() {
+        sp_io::TestExternalities::default().execute_with(|| {
+            DoubleMap::insert(1, 1, 10);
+            DoubleMap::insert(2, 2, 20);
+
+            DoubleMap::relocate(1, 1, 2, 2);
+            assert!(!DoubleMap::contains_key(1, 1));
+            assert_eq!(DoubleMap::try_get(2, 2), Ok(10));
+
+            // Relocating from an empty key-pair leaves the destination untouched.
+            DoubleMap::relocate(1, 1, 2, 2);
+            assert!(!DoubleMap::contains_key(1, 1));
+            assert_eq!(DoubleMap::try_get(2, 2), Ok(10));
+        });
+    }
+
     #[test]
     fn double_map_reversible_reversible_iteration() {
         sp_io::TestExternalities::default().execute_with(|| {
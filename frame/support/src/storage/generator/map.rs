@@ -145,6 +145,7 @@ where
             prefix: prefix.clone(),
             previous_key: prefix,
             drain: false,
+            limit: None,
             closure: |raw_key_without_prefix, mut raw_value| {
                 let mut key_material = G::Hasher::reverse(raw_key_without_prefix);
                 Ok((K::decode(&mut key_material)?, V::decode(&mut raw_value)?))
@@ -329,7 +330,7 @@ impl<K: FullEncode, V: FullCodec, G: StorageMap<K, V>> storage::StorageMap<K, V>
 mod test_iterators {
     use crate::{
         hash::StorageHasher,
-        storage::{generator::StorageMap, unhashed, IterableStorageMap},
+        storage::{generator::StorageMap, unhashed, IterableStorageMap, StorageMap as _},
     };
     use codec::{Decode, Encode};
 
@@ -370,6 +371,96 @@ mod test_iterators {
         prefix
     }
 
+    #[test]
+    fn try_get_or_insert_with_works() {
+        sp_io::TestExternalities::default().execute_with(|| {
+            let mut called = false;
+            let value = Map::try_get_or_insert_with(1, || {
+                called = true;
+                42
+            });
+            assert!(called);
+            assert_eq!(value, 42);
+            assert_eq!(Map::try_get(1), Ok(42));
+
+            let value = Map::try_get_or_insert_with(1, || unreachable!("value is present"));
+            assert_eq!(value, 42);
+            assert_eq!(Map::try_get(1), Ok(42));
+        });
+    }
+
+    #[test]
+    fn mutate_extant_works() {
+        sp_io::TestExternalities::default().execute_with(|| {
+            let result = Map::mutate_extant(1, |_| -> bool { unreachable!("value is absent") });
+            assert!(!result);
+            assert!(!Map::contains_key(1));
+
+            Map::insert(1, 42);
+            let result = Map::mutate_extant(1, |value| {
+                *value += 1;
+                true
+            });
+            assert!(result);
+            assert_eq!(Map::try_get(1), Ok(43));
+        });
+    }
+
+    #[test]
+    fn drain_all_works() {
+        sp_io::TestExternalities::default().execute_with(|| {
+            for i in 0..4 {
+                Map::insert(i as u16, i as u64);
+            }
+
+            assert_eq!(Map::drain_all(), vec![(3, 3), (0, 0), (2, 2), (1, 1)]);
+            assert_eq!(Map::iter().count(), 0);
+        });
+    }
+
+    #[test]
+    fn get_many_works() {
+        sp_io::TestExternalities::default().execute_with(|| {
+            Map::insert(1, 10);
+            Map::insert(2, 20);
+            Map::insert(3, 30);
+
+            assert_eq!(Map::get_many(&[3, 4, 1]), vec![30, 0, 10]);
+            assert_eq!(Map::get_many::<u16>(&[]), Vec::<u64>::new());
+        });
+    }
+
+    #[test]
+    fn migrate_keys_from_works() {
+        sp_io::TestExternalities::default().execute_with(|| {
+            let prefix = Map::prefix_hash();
+            for i in 0..3u16 {
+                unhashed::put(
+                    &[
+                        prefix.clone(),
+                        crate::Blake2_256::hash(&i.encode()).to_vec(),
+                    ]
+                    .concat(),
+                    &(i as u64 * 10),
+                );
+            }
+
+            assert_eq!(
+                Map::migrate_keys_from::<crate::Blake2_256>(vec![0, 2].into_iter()),
+                2
+            );
+            assert_eq!(Map::try_get(0), Ok(0));
+            assert_eq!(Map::try_get(2), Ok(20));
+            assert!(!Map::contains_key(1));
+
+            // Already migrated or absent keys are skipped.
+            assert_eq!(
+                Map::migrate_keys_from::<crate::Blake2_256>(vec![2, 5].into_iter()),
+                0
+            );
+        });
+    }
+
     #[test]
     fn map_reversible_reversible_iteration() {
         sp_io::TestExternalities::default().execute_with(|| {
@@ -109,6 +109,28 @@ pub fn with_transaction<R>(f: impl FnOnce() -> TransactionOutcome<R>) -> R {
     }
 }
 
+/// Swap the contents of the storage values `A` and `B`.
+///
+/// Both storage values must hold the same type `T`. The raw values are exchanged without being
+/// decoded, so an absent value stays absent under the other storage value and each side keeps
+/// translating it through its own query type.
+pub fn swap_values<T: FullCodec, A: StorageValue<T>, B: StorageValue<T>>() {
+    let key_a = A::hashed_key();
+    let key_b = B::hashed_key();
+
+    let value_a = unhashed::get_raw(&key_a);
+    if let Some(value_b) = unhashed::get_raw(&key_b) {
+        unhashed::put_raw(&key_a, &value_b);
+    } else {
+        unhashed::kill(&key_a)
+    }
+    if let Some(value_a) = value_a {
+        unhashed::put_raw(&key_b, &value_a);
+    } else {
+        unhashed::kill(&key_b)
+    }
+}
+
 /// A trait for working with macro-generated storage values under the substrate storage API.
 ///
 /// Details on implementation can be found at [`generator::StorageValue`].
@@ -203,6 +225,24 @@ pub trait StorageValue<T: FullCodec> {
     {
         T::decode_len(&Self::hashed_key())
     }
+
+    /// Check whether the raw value exists in storage and read its length separately.
+    ///
+    /// Unlike [`Self::decode_len`], this allows to tell a missing value (`(false, None)`) apart
+    /// from a value that is present but whose length can't be decoded (`(true, None)`).
+    fn exists_and_len() -> (bool, Option<usize>)
+    where
+        T: StorageDecodeLength,
+    {
+        (Self::exists(), Self::decode_len())
+    }
+
+    /// Get the value stored in storage, or `fallback` if there is none.
+    ///
+    /// Unlike [`Self::get`], this ignores the default value configured for the storage item.
+    fn get_or(fallback: T) -> T {
+        Self::try_get().unwrap_or(fallback)
+    }
 }
 
 /// A strongly-typed map in storage.
@@ -226,6 +266,14 @@ pub trait StorageMap<K: FullEncode, V: FullCodec> {
     /// Returns `Ok` if it exists, `Err` if not.
     fn try_get<KeyArg: EncodeLike<K>>(key: KeyArg) -> Result<V, ()>;
 
+    /// Load the values associated with each of the given keys from the map, in the order of
+    /// `keys`.
+    ///
+    /// Each key is still read separately.
+    fn get_many<KeyArg: EncodeLike<K> + Clone>(keys: &[KeyArg]) -> Vec<Self::Query> {
+        keys.iter().cloned().map(Self::get).collect()
+    }
+
     /// Swap the values of two keys.
     fn swap<KeyArg1: EncodeLike<K>, KeyArg2: EncodeLike<K>>(key1: KeyArg1, key2: KeyArg2);
 
@@ -261,6 +309,31 @@ pub trait StorageMap<K: FullEncode, V: FullCodec> {
     /// Take the value under a key.
     fn take<KeyArg: EncodeLike<K>>(key: KeyArg) -> Self::Query;
 
+    /// Get the value under a key, or insert and return the result of `f` if none exists.
+    ///
+    /// `f` is only called if there is no value under the key.
+    fn try_get_or_insert_with<KeyArg: EncodeLike<K>, F: FnOnce() -> V>(key: KeyArg, f: F) -> V
+    where
+        V: Clone,
+    {
+        Self::mutate_exists(key, |maybe_value| maybe_value.get_or_insert_with(f).clone())
+    }
+
+    /// Mutate the value under a key only if it exists.
+    ///
+    /// `f` operates on the stored value itself rather than on `Self::Query`. If there is no
+    /// value under the key, `f` is not called, nothing is written and `R::default()` is returned.
+    fn mutate_extant<KeyArg: EncodeLike<K>, R: Default, F: FnOnce(&mut V) -> R>(
+        key: KeyArg,
+        f: F,
+    ) -> R {
+        Self::try_mutate_exists(key, |maybe_value| match maybe_value {
+            Some(value) => Ok(f(value)),
+            None => Err(()),
+        })
+        .unwrap_or_default()
+    }
+
     /// Append the given items to the value in the storage.
     ///
     /// `V` is required to implement `codec::EncodeAppend`.
@@ -307,6 +380,14 @@ pub trait StorageMap<K: FullEncode, V: FullCodec> {
     fn migrate_key_from_blake<KeyArg: EncodeLike<K>>(key: KeyArg) -> Option<V> {
         Self::migrate_key::<crate::hash::Blake2_256, KeyArg>(key)
     }
+
+    /// Migrate each of the given `keys` from a defunct `OldHasher` to the current hasher.
+    ///
+    /// Keys that don't exist are skipped. Returns the number of items that were moved.
+    fn migrate_keys_from<OldHasher: StorageHasher>(keys: impl Iterator<Item = K>) -> u32 {
+        keys.filter(|key| Self::migrate_key::<OldHasher, _>(key).is_some())
+            .count() as u32
+    }
 }
 
 /// A strongly-typed map in storage whose keys and values can be iterated over.
@@ -322,6 +403,13 @@ pub trait IterableStorageMap<K: FullEncode, V: FullCodec>: StorageMap<K, V> {
     /// add elements to the map while doing this, you'll get undefined results.
     fn drain() -> Self::Iterator;
 
+    /// Remove all elements from the map and return them in no particular order.
+    ///
+    /// Unlike [`Self::drain`] this drains eagerly, so the map is empty once it returns.
+    fn drain_all() -> Vec<(K, V)> {
+        Self::drain().collect()
+    }
+
     /// Translate the values of all elements by a function `f`, in the map in no particular order.
     /// By returning `None` from `f` for an element, you'll remove it from the map.
     ///
@@ -344,11 +432,24 @@ pub trait IterableStorageDoubleMap<K1: FullCodec, K2: FullCodec, V: FullCodec>:
     /// results.
     fn iter_prefix(k1: impl EncodeLike<K1>) -> Self::PrefixIterator;
 
+    /// Enumerate all second keys `k2` in the map with first key `k1` in no particular order,
+    /// without decoding the values. If you add or remove values whose first key is `k1` to the
+    /// map while doing this, you'll get undefined results.
+    fn iter_key_prefix(k1: impl EncodeLike<K1>) -> KeyPrefixIterator<K2>;
+
     /// Remove all elements from the map with first key `k1` and iterate through them in no
     /// particular order. If you add elements with first key `k1` to the map while doing this,
     /// you'll get undefined results.
     fn drain_prefix(k1: impl EncodeLike<K1>) -> Self::PrefixIterator;
 
+    /// Remove at most `limit` elements from the map with first key `k1` and iterate through them
+    /// in no particular order. If you add elements with first key `k1` to the map while doing
+    /// this, you'll get undefined results.
+    ///
+    /// Also returns how many elements with first key `k1` are left in the map once the iterator
+    /// is exhausted, so that the draining can be resumed later.
+    fn drain_prefix_limited(k1: impl EncodeLike<K1>, limit: u32) -> (Self::PrefixIterator, u32);
+
     /// Enumerate all elements in the map in no particular order. If you add or remove values to
     /// the map while doing this, you'll get undefined results.
     fn iter() -> Self::Iterator;
@@ -414,6 +515,21 @@ pub trait StorageDoubleMap<K1: FullEncode, K2: FullEncode, V: FullCodec> {
         YKArg1: EncodeLike<K1>,
         YKArg2: EncodeLike<K2>;
 
+    /// Move the value under the `from` key-pair to the `to` key-pair, overwriting any value
+    /// there.
+    ///
+    /// If there is no value under the `from` key-pair, the `to` key-pair is left untouched.
+    fn relocate<XKArg1, XKArg2, YKArg1, YKArg2>(
+        from_k1: XKArg1,
+        from_k2: XKArg2,
+        to_k1: YKArg1,
+        to_k2: YKArg2,
+    ) where
+        XKArg1: EncodeLike<K1>,
+        XKArg2: EncodeLike<K2>,
+        YKArg1: EncodeLike<K1>,
+        YKArg2: EncodeLike<K2>;
+
     /// Store a value to be associated with the given keys from the double map.
     fn insert<KArg1, KArg2, VArg>(k1: KArg1, k2: KArg2, val: VArg)
     where
@@ -526,6 +642,8 @@ pub struct PrefixIterator<T> {
     previous_key: Vec<u8>,
     /// If true then value are removed while iterating
     drain: bool,
+    /// If set, the number of keys that can still be visited before the iteration stops.
+    limit: Option<u32>,
     /// Function that take `(raw_key_without_prefix, raw_value)` and decode `T`.
     /// `raw_key_without_prefix` is the raw storage key without the prefix iterated on.
     closure: fn(&[u8], &[u8]) -> Result<T, codec::Error>,
@@ -536,11 +654,17 @@ impl<T> Iterator for PrefixIterator<T> {
 
     fn next(&mut self) -> Option<Self::Item> {
         loop {
+            if self.limit == Some(0) {
+                return None;
+            }
             let maybe_next = sp_io::storage::next_key(&self.previous_key)
                 .filter(|n| n.starts_with(&self.prefix));
             break match maybe_next {
                 Some(next) => {
                     self.previous_key = next;
+                    if let Some(limit) = self.limit.as_mut() {
+                        *limit -= 1;
+                    }
                     let raw_value = match unhashed::get_raw(&self.previous_key) {
                         Some(raw_value) => raw_value,
                         None => {
@@ -575,6 +699,46 @@ impl<T> Iterator for PrefixIterator<T> {
     }
 }
 
+/// Iterate over a prefix and decode raw_key into `T`, without reading the values.
+///
+/// If any decoding fails it skips it and continues to the next key.
+pub struct KeyPrefixIterator<T> {
+    prefix: Vec<u8>,
+    previous_key: Vec<u8>,
+    /// Function that take `raw_key_without_prefix` and decode `T`.
+    /// `raw_key_without_prefix` is the raw storage key without the prefix iterated on.
+    closure: fn(&[u8]) -> Result<T, codec::Error>,
+}
+
+impl<T> Iterator for KeyPrefixIterator<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let maybe_next = sp_io::storage::next_key(&self.previous_key)
+                .filter(|n| n.starts_with(&self.prefix));
+            break match maybe_next {
+                Some(next) => {
+                    self.previous_key = next;
+                    let raw_key_without_prefix = &self.previous_key[self.prefix.len()..];
+                    match (self.closure)(raw_key_without_prefix) {
+                        Ok(item) => Some(item),
+                        Err(e) => {
+                            crate::debug::error!(
+                                "key failed to decode at {:?}: {:?}",
+                                self.previous_key,
+                                e
+                            );
+                            continue;
+                        }
+                    }
+                }
+                None => None,
+            };
+        }
+    }
+}
+
 /// Trait for maps that store all its value after a unique prefix.
 ///
 /// By default the final prefix is:
@@ -610,10 +774,28 @@ pub trait StoragePrefixedMap<Value: FullCodec> {
             prefix: prefix.to_vec(),
             previous_key: prefix.to_vec(),
             drain: false,
+            limit: None,
             closure: |_raw_key, mut raw_value| Value::decode(&mut raw_value),
         }
     }
 
+    /// Count the number of elements in the storage, without decoding their values.
+    ///
+    /// NOTE: This is O(n) in the number of keys. Unlike counting `iter_values`, entries whose
+    /// value fails to decode are counted too.
+    fn count() -> usize {
+        let prefix = Self::final_prefix();
+        let mut previous_key = prefix.to_vec();
+        let mut count = 0;
+        while let Some(next) =
+            sp_io::storage::next_key(&previous_key).filter(|n| n.starts_with(&prefix))
+        {
+            previous_key = next;
+            count += 1;
+        }
+        count
+    }
+
     /// Translate the values of all elements by a function `f`, in the map in no particular order.
     /// By returning `None` from `f` for an element, you'll remove it from the map.
     ///
@@ -736,6 +918,7 @@ mod test {
 
             // test iteration
             assert!(MyStorage::iter_values().collect::<Vec<_>>().is_empty());
+            assert_eq!(MyStorage::count(), 0);
 
             unhashed::put(&[&k[..], &vec![1][..]].concat(), &1u64);
             unhashed::put(&[&k[..], &vec![1, 1][..]].concat(), &2u64);
@@ -768,6 +951,9 @@ mod test {
 
             // (contains some value that successfully decoded to u64)
             assert_eq!(MyStorage::iter_values().collect::<Vec<_>>(), vec![1, 2, 3]);
+            // counting doesn't decode, so the undecodable value is counted too.
+            assert_eq!(MyStorage::count(), 4);
+            assert_eq!(MyStorage::iter_values().count(), 3);
             MyStorage::translate_values(|v: u128| Some(v as u64));
             assert_eq!(MyStorage::iter_values().collect::<Vec<_>>(), vec![1, 2, 3]);
             MyStorage::remove_all();
@@ -818,6 +1004,65 @@ mod test {
         });
     }
 
+    #[test]
+    fn swap_values_works() {
+        TestExternalities::default().execute_with(|| {
+            struct ValueA;
+            impl generator::StorageValue<u32> for ValueA {
+                type Query = Option<u32>;
+
+                fn module_prefix() -> &'static [u8] {
+                    b"MyModule"
+                }
+
+                fn storage_prefix() -> &'static [u8] {
+                    b"ValueA"
+                }
+
+                fn from_optional_value_to_query(v: Option<u32>) -> Self::Query {
+                    v
+                }
+
+                fn from_query_to_optional_value(v: Self::Query) -> Option<u32> {
+                    v
+                }
+            }
+
+            struct ValueB;
+            impl generator::StorageValue<u32> for ValueB {
+                type Query = u32;
+
+                fn module_prefix() -> &'static [u8] {
+                    b"MyModule"
+                }
+
+                fn storage_prefix() -> &'static [u8] {
+                    b"ValueB"
+                }
+
+                fn from_optional_value_to_query(v: Option<u32>) -> Self::Query {
+                    v.unwrap_or_default()
+                }
+
+                fn from_query_to_optional_value(v: Self::Query) -> Option<u32> {
+                    Some(v)
+                }
+            }
+
+            ValueA::put(1);
+            ValueB::put(2);
+            swap_values::<u32, ValueA, ValueB>();
+            assert_eq!(ValueA::get(), Some(2));
+            assert_eq!(ValueB::get(), 1);
+
+            // An absent value is moved over as absent.
+            ValueA::kill();
+            swap_values::<u32, ValueA, ValueB>();
+            assert_eq!(ValueA::get(), Some(1));
+            assert!(!ValueB::exists());
+        });
+    }
+
     #[test]
     #[should_panic(expected = "Require transaction not called within with_transaction")]
     fn require_transaction_should_panic() {
@@ -192,6 +192,26 @@ where
         <Self as crate::storage::StorageDoubleMap<Key1, Key2, Value>>::swap(x_k1, x_k2, y_k1, y_k2)
     }
 
+    /// Move the value under the `from` key-pair to the `to` key-pair, overwriting any value
+    /// there.
+    ///
+    /// If there is no value under the `from` key-pair, the `to` key-pair is left untouched.
+    pub fn relocate<XKArg1, XKArg2, YKArg1, YKArg2>(
+        from_k1: XKArg1,
+        from_k2: XKArg2,
+        to_k1: YKArg1,
+        to_k2: YKArg2,
+    ) where
+        XKArg1: EncodeLike<Key1>,
+        XKArg2: EncodeLike<Key2>,
+        YKArg1: EncodeLike<Key1>,
+        YKArg2: EncodeLike<Key2>,
+    {
+        <Self as crate::storage::StorageDoubleMap<Key1, Key2, Value>>::relocate(
+            from_k1, from_k2, to_k1, to_k2,
+        )
+    }
+
     /// Store a value to be associated with the given keys from the double map.
     pub fn insert<KArg1, KArg2, VArg>(k1: KArg1, k2: KArg2, val: VArg)
     where
@@ -379,6 +399,15 @@ where
         <Self as crate::storage::IterableStorageDoubleMap<Key1, Key2, Value>>::iter_prefix(k1)
     }
 
+    /// Enumerate all second keys `k2` in the map with first key `k1` in no particular order,
+    /// without decoding the values.
+    ///
+    /// If you add or remove values whose first key is `k1` to the map while doing this, you'll get
+    /// undefined results.
+    pub fn iter_key_prefix(k1: impl EncodeLike<Key1>) -> crate::storage::KeyPrefixIterator<Key2> {
+        <Self as crate::storage::IterableStorageDoubleMap<Key1, Key2, Value>>::iter_key_prefix(k1)
+    }
+
     /// Remove all elements from the map with first key `k1` and iterate through them in no
     /// particular order.
     ///
@@ -390,6 +419,20 @@ where
         <Self as crate::storage::IterableStorageDoubleMap<Key1, Key2, Value>>::drain_prefix(k1)
     }
 
+    /// Remove at most `limit` elements from the map with first key `k1` and iterate through them
+    /// in no particular order.
+    ///
+    /// Also returns how many elements with first key `k1` are left once the iterator is
+    /// exhausted.
+    pub fn drain_prefix_limited(
+        k1: impl EncodeLike<Key1>,
+        limit: u32,
+    ) -> (crate::storage::PrefixIterator<(Key2, Value)>, u32) {
+        <Self as crate::storage::IterableStorageDoubleMap<Key1, Key2, Value>>::drain_prefix_limited(
+            k1, limit,
+        )
+    }
+
     /// Enumerate all elements in the map in no particular order.
     ///
     /// If you add or remove values to the map while doing this, you'll get undefined results.
@@ -123,6 +123,12 @@ where
         <Self as crate::storage::StorageMap<Key, Value>>::try_get(key)
     }
 
+    /// Load the values associated with each of the given keys from the map, in the order of
+    /// `keys`.
+    pub fn get_many<KeyArg: EncodeLike<Key> + Clone>(keys: &[KeyArg]) -> Vec<QueryKind::Query> {
+        <Self as crate::storage::StorageMap<Key, Value>>::get_many(keys)
+    }
+
     /// Swap the values of two keys.
     pub fn swap<KeyArg1: EncodeLike<Key>, KeyArg2: EncodeLike<Key>>(key1: KeyArg1, key2: KeyArg2) {
         <Self as crate::storage::StorageMap<Key, Value>>::swap(key1, key2)
@@ -224,6 +230,15 @@ where
         <Self as crate::storage::StorageMap<Key, Value>>::migrate_key::<OldHasher, _>(key)
     }
 
+    /// Migrate each of the given `keys` from a defunct `OldHasher` to the current hasher.
+    ///
+    /// Keys that don't exist are skipped. Returns the number of items that were moved.
+    pub fn migrate_keys_from<OldHasher: crate::hash::StorageHasher>(
+        keys: impl Iterator<Item = Key>,
+    ) -> u32 {
+        <Self as crate::storage::StorageMap<Key, Value>>::migrate_keys_from::<OldHasher>(keys)
+    }
+
     /// Remove all value of the storage.
     pub fn remove_all() {
         <Self as crate::storage::StoragePrefixedMap<Value>>::remove_all()
@@ -279,6 +294,13 @@ where
         <Self as crate::storage::IterableStorageMap<Key, Value>>::drain()
     }
 
+    /// Remove all elements from the map and return them in no particular order.
+    ///
+    /// Unlike [`Self::drain`] this drains eagerly, so the map is empty once it returns.
+    pub fn drain_all() -> Vec<(Key, Value)> {
+        <Self as crate::storage::IterableStorageMap<Key, Value>>::drain_all()
+    }
+
     /// Translate the values of all elements by a function `f`, in the map in no particular order.
     ///
     /// By returning `None` from `f` for an element, you'll remove it from the map.
@@ -184,6 +184,23 @@ where
     {
         <Self as crate::storage::StorageValue<Value>>::decode_len()
     }
+
+    /// Check whether the raw value exists in storage and read its length separately.
+    ///
+    /// `(true, None)` means the value is present but its length fails to decode.
+    pub fn exists_and_len() -> (bool, Option<usize>)
+    where
+        Value: StorageDecodeLength,
+    {
+        <Self as crate::storage::StorageValue<Value>>::exists_and_len()
+    }
+
+    /// Get the value stored in storage, or `fallback` if there is none.
+    ///
+    /// The `OnEmpty` default is ignored.
+    pub fn get_or(fallback: Value) -> Value {
+        <Self as crate::storage::StorageValue<Value>>::get_or(fallback)
+    }
 }
 
 /// Part of storage metadata for storage value.
@@ -293,6 +310,10 @@ mod test {
 
             A::kill();
             assert_eq!(A::try_get(), Err(()));
+            assert_eq!(A::get_or(5), 5);
+            assert_eq!(AValueQueryWithAnOnEmpty::get_or(5), 97 * 2);
+            AValueQueryWithAnOnEmpty::kill();
+            assert_eq!(AValueQueryWithAnOnEmpty::get_or(5), 5);
 
             assert_eq!(A::MODIFIER, StorageEntryModifier::Optional);
             assert_eq!(
@@ -310,6 +331,13 @@ mod test {
             assert_eq!(WithLen::decode_len(), None);
             WithLen::append(3);
             assert_eq!(WithLen::decode_len(), Some(1));
+            assert_eq!(WithLen::exists_and_len(), (true, Some(1)));
+
+            WithLen::kill();
+            assert_eq!(WithLen::exists_and_len(), (false, None));
+            // a compact length prefix announcing more bytes than there are.
+            sp_io::storage::set(&WithLen::hashed_key(), &[0xff]);
+            assert_eq!(WithLen::exists_and_len(), (true, None));
         });
     }
 }
@@ -31,6 +31,9 @@ const MAX_TIME: u32 = 100;
 
 benchmarks! {
     set {
+        let w in 0 .. T::MedianWindow::get();
+        // Fill the recent timestamps ring buffer with `w` entries.
+        RecentTimestamps::<T>::put(vec![T::Moment::zero(); w as usize]);
         let t = MAX_TIME;
         // Ignore write to `DidUpdate` since it transient.
         let did_update_key = crate::DidUpdate::<T>::hashed_key().to_vec();
@@ -45,10 +45,14 @@
 //!
 //! * `get` - Gets the current time for the current block. If this function is called prior to
 //! setting the timestamp, it will return the timestamp of the previous block.
+//! * `median_timestamp` - Gets the median of the timestamps of the last `MedianWindow` blocks.
 //!
 //! ### Config Getters
 //!
 //! * `MinimumPeriod` - Gets the minimum (and advised) period between blocks for the chain.
+//! * `MedianWindow` - Gets the number of recent timestamps the median is computed over.
+//! * `MaxPastDriftMillis` - Gets how far a timestamp may lag behind the clock of the node checking
+//! it. A finite value keeps nodes from syncing blocks older than that, see the config item.
 //!
 //! ## Usage
 //!
@@ -97,18 +101,24 @@ pub mod weights;
 
 #[cfg(feature = "std")]
 use frame_support::debug;
-use frame_support::traits::{Time, UnixTime};
+use frame_support::traits::{Get, Time, UnixTime};
 use sp_inherents::InherentData;
 use sp_runtime::{
     traits::{AtLeast32Bit, SaturatedConversion, Scale, Zero},
     RuntimeString,
 };
-use sp_std::{cmp, result};
+use sp_std::{cmp, prelude::*, result};
 use sp_timestamp::{InherentError, InherentType, OnTimestampSet, INHERENT_IDENTIFIER};
 pub use weights::WeightInfo;
 
 pub use pallet::*;
 
+#[cfg(any(test, feature = "test-utils"))]
+std::thread_local! {
+    /// Minimum period set by `override_minimum_period`, in the units of `Config::Moment`.
+    static MINIMUM_PERIOD_OVERRIDE: std::cell::Cell<Option<u64>> = std::cell::Cell::new(None);
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -135,6 +145,20 @@ pub mod pallet {
         #[pallet::constant]
         type MinimumPeriod: Get<Self::Moment>;
 
+        /// The number of most recent block timestamps `median_timestamp` is computed over.
+        #[pallet::constant]
+        type MedianWindow: Get<u32>;
+
+        /// How far, in milliseconds, a timestamp may lag behind the local clock of the node
+        /// checking it. Set this to `u64::max_value()` to accept any timestamp in the past.
+        ///
+        /// Beware that the inherent is checked on every block import against the clock of the
+        /// importing node. A node syncing old blocks sees their timestamps lag behind by up to the
+        /// age of the chain and rejects every block older than this value, so it can never catch
+        /// up. Only use a finite value on chains whose nodes all follow the tip, e.g. test chains.
+        #[pallet::constant]
+        type MaxPastDriftMillis: Get<u64>;
+
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
     }
@@ -152,6 +176,14 @@ pub mod pallet {
     #[pallet::storage]
     pub(super) type DidUpdate<T: Config> = StorageValue<_, bool, ValueQuery>;
 
+    /// Ring buffer of the timestamps of the last `MedianWindow` blocks.
+    #[pallet::storage]
+    pub(super) type RecentTimestamps<T: Config> = StorageValue<_, Vec<T::Moment>, ValueQuery>;
+
+    /// Position in `RecentTimestamps` that is written by the next timestamp.
+    #[pallet::storage]
+    pub(super) type RecentTimestampsCursor<T: Config> = StorageValue<_, u32, ValueQuery>;
+
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
         /// dummy `on_initialize` to return the weight used in `on_finalize`.
@@ -185,12 +217,15 @@ pub mod pallet {
         /// The dispatch origin for this call must be `Inherent`.
         ///
         /// # <weight>
-        /// - `O(1)` (Note that implementations of `OnTimestampSet` must also be `O(1)`)
+        /// - `O(W)` where `W` is `MedianWindow` (Note that implementations of `OnTimestampSet`
+        ///   must be `O(1)`)
         /// - 1 storage read and 1 storage mutation (codec `O(1)`). (because of `DidUpdate::take` in `on_finalize`)
+        /// - 1 storage read and 1 storage mutation of the recent timestamps ring buffer (codec `O(W)`).
+        /// - 1 storage read and 1 storage mutation of the ring buffer cursor (codec `O(1)`).
         /// - 1 event handler `on_timestamp_set`. Must be `O(1)`.
         /// # </weight>
         #[pallet::weight((
-			T::WeightInfo::set(),
+			T::WeightInfo::set(T::MedianWindow::get()),
 			DispatchClass::Mandatory
 		))]
         pub(super) fn set(
@@ -204,11 +239,12 @@ pub mod pallet {
             );
             let prev = Self::now();
             assert!(
-                prev.is_zero() || now >= prev + T::MinimumPeriod::get(),
+                prev.is_zero() || now >= prev + Self::minimum_period(),
                 "Timestamp must increment by at least <MinimumPeriod> between sequential blocks"
             );
             Now::<T>::put(now);
             DidUpdate::<T>::put(true);
+            Self::note_recent_timestamp(now);
 
             <T::OnTimestampSet as OnTimestampSet<_>>::on_timestamp_set(now);
 
@@ -227,7 +263,7 @@ pub mod pallet {
                 .expect("Gets and decodes timestamp inherent data")
                 .saturated_into();
 
-            let next_time = cmp::max(data, Self::now() + T::MinimumPeriod::get());
+            let next_time = cmp::max(data, Self::now() + Self::minimum_period());
             Some(Call::set(next_time.into()))
         }
 
@@ -244,11 +280,16 @@ pub mod pallet {
 
             let data = extract_inherent_data(data).map_err(|e| InherentError::Other(e))?;
 
-            let minimum = (Self::now() + T::MinimumPeriod::get()).saturated_into::<u64>();
+            let minimum = (Self::now() + Self::minimum_period()).saturated_into::<u64>();
             if t > data + MAX_TIMESTAMP_DRIFT_MILLIS {
                 Err(InherentError::Other(
                     "Timestamp too far in future to accept".into(),
                 ))
+            } else if t.saturating_add(T::MaxPastDriftMillis::get()) < data {
+                // NOTE: this also rejects old blocks while syncing, see `MaxPastDriftMillis`.
+                Err(InherentError::Other(
+                    "Timestamp too far in past to accept".into(),
+                ))
             } else if t < minimum {
                 Err(InherentError::ValidAtTimestamp(minimum))
             } else {
@@ -267,11 +308,98 @@ impl<T: Config> Pallet<T> {
         Self::now()
     }
 
+    /// Whether the timestamp has already been set in the current block.
+    ///
+    /// This turns `true` once the `set` inherent executed and is reset in `on_finalize`.
+    pub fn is_set_this_block() -> bool {
+        DidUpdate::<T>::get()
+    }
+
+    /// Get the median of the timestamps of the last `MedianWindow` blocks.
+    ///
+    /// Returns `None` until `MedianWindow` timestamps have been recorded. For an even window
+    /// the upper of the two middle values is returned.
+    pub fn median_timestamp() -> Option<T::Moment> {
+        let window = T::MedianWindow::get() as usize;
+        let mut recent = RecentTimestamps::<T>::get();
+        if window == 0 || recent.len() < window {
+            return None;
+        }
+
+        recent.sort_unstable();
+        Some(recent[recent.len() / 2])
+    }
+
+    /// Record `now` in the recent timestamps ring buffer, overwriting the oldest entry once
+    /// the buffer holds `MedianWindow` timestamps.
+    fn note_recent_timestamp(now: T::Moment) {
+        let window = T::MedianWindow::get() as usize;
+        if window == 0 {
+            return;
+        }
+
+        let cursor = RecentTimestampsCursor::<T>::get() as usize % window;
+        RecentTimestamps::<T>::mutate(|recent| {
+            // The window might have shrunk since the last runtime upgrade.
+            recent.truncate(window);
+            if cursor < recent.len() {
+                recent[cursor] = now;
+            } else {
+                recent.push(now);
+            }
+        });
+        RecentTimestampsCursor::<T>::put(((cursor + 1) % window) as u32);
+    }
+
+    /// The minimum period between blocks that is enforced by `set` and the inherent.
+    ///
+    /// This is `MinimumPeriod` unless overridden by `override_minimum_period`.
+    fn minimum_period() -> T::Moment {
+        #[cfg(any(test, feature = "test-utils"))]
+        {
+            if let Some(period) = MINIMUM_PERIOD_OVERRIDE.with(|o| o.get()) {
+                return period.saturated_into();
+            }
+        }
+        T::MinimumPeriod::get()
+    }
+
+    /// Override `MinimumPeriod` for the current thread. Only used for tests.
+    ///
+    /// Only available with the opt-in `test-utils` feature, which a runtime must never enable.
+    #[cfg(any(test, feature = "test-utils"))]
+    pub fn override_minimum_period(period: T::Moment) {
+        MINIMUM_PERIOD_OVERRIDE.with(|o| o.set(Some(period.saturated_into())));
+    }
+
+    /// Remove the override set by `override_minimum_period`. Only used for tests.
+    #[cfg(any(test, feature = "test-utils"))]
+    pub fn clear_minimum_period_override() {
+        MINIMUM_PERIOD_OVERRIDE.with(|o| o.set(None));
+    }
+
     /// Set the timestamp to something in particular. Only used for tests.
     #[cfg(feature = "std")]
     pub fn set_timestamp(now: T::Moment) {
         Now::<T>::put(now);
     }
+
+    /// Set the timestamp from a duration since the unix epoch. Only used for tests.
+    ///
+    /// Saturates at `T::Moment::max_value()` if the duration in milliseconds does not fit.
+    #[cfg(feature = "std")]
+    pub fn set_timestamp_from_duration(d: core::time::Duration) {
+        Now::<T>::put(d.as_millis().saturated_into::<T::Moment>());
+    }
+}
+
+/// Build an `InherentData` carrying the given timestamp. Only used for tests.
+#[cfg(feature = "std")]
+pub fn inherent_data_with_timestamp(now: InherentType) -> InherentData {
+    let mut data = InherentData::new();
+    data.put_data(INHERENT_IDENTIFIER, &now)
+        .expect("Encoding the timestamp into fresh inherent data can't fail; qed");
+    data
 }
 
 fn extract_inherent_data(data: &InherentData) -> Result<InherentType, RuntimeString> {
@@ -313,7 +441,7 @@ mod tests {
     use super::*;
     use crate as pallet_timestamp;
 
-    use frame_support::{assert_ok, parameter_types};
+    use frame_support::{assert_ok, inherent::ProvideInherent, parameter_types};
     use sp_core::H256;
     use sp_io::TestExternalities;
     use sp_runtime::{
@@ -373,11 +501,33 @@ mod tests {
     }
     parameter_types! {
         pub const MinimumPeriod: u64 = 5;
+        pub const MedianWindow: u32 = 5;
+        pub static MaxPastDriftMillis: u64 = u64::max_value();
+    }
+    thread_local! {
+        static OBSERVED: std::cell::RefCell<Vec<(&'static str, u64)>> = Default::default();
+    }
+
+    pub struct RecordFirst;
+    impl OnTimestampSet<u64> for RecordFirst {
+        fn on_timestamp_set(moment: u64) {
+            OBSERVED.with(|o| o.borrow_mut().push(("first", moment)));
+        }
     }
+
+    pub struct RecordSecond;
+    impl OnTimestampSet<u64> for RecordSecond {
+        fn on_timestamp_set(moment: u64) {
+            OBSERVED.with(|o| o.borrow_mut().push(("second", moment)));
+        }
+    }
+
     impl Config for Test {
         type Moment = u64;
-        type OnTimestampSet = ();
+        type OnTimestampSet = (RecordFirst, RecordSecond);
         type MinimumPeriod = MinimumPeriod;
+        type MedianWindow = MedianWindow;
+        type MaxPastDriftMillis = MaxPastDriftMillis;
         type WeightInfo = ();
     }
 
@@ -390,6 +540,135 @@ mod tests {
         });
     }
 
+    #[test]
+    fn check_inherent_rejects_past_drift() {
+        new_test_ext().execute_with(|| {
+            let inherent_data = inherent_data_with_timestamp(10_000);
+            let check = |t| Timestamp::check_inherent(&crate::Call::set(t), &inherent_data);
+
+            // any past timestamp above the minimum is accepted by default.
+            assert!(check(10).is_ok());
+
+            MaxPastDriftMillis::set(1_000);
+            assert!(matches!(check(8_000), Err(InherentError::Other(_))));
+            assert!(check(9_500).is_ok());
+        });
+    }
+
+    #[test]
+    fn is_set_this_block_works() {
+        new_test_ext().execute_with(|| {
+            assert!(!Timestamp::is_set_this_block());
+            assert_ok!(Timestamp::set(Origin::none(), 69));
+            assert!(Timestamp::is_set_this_block());
+            <Timestamp as frame_support::traits::OnFinalize<u64>>::on_finalize(1);
+            assert!(!Timestamp::is_set_this_block());
+        });
+    }
+
+    #[test]
+    fn set_timestamp_from_duration_roundtrips() {
+        new_test_ext().execute_with(|| {
+            let duration = core::time::Duration::from_millis(1_600_000_000_123);
+            Timestamp::set_timestamp_from_duration(duration);
+            assert_eq!(<Timestamp as UnixTime>::now(), duration);
+
+            Timestamp::set_timestamp_from_duration(core::time::Duration::from_secs(
+                u64::max_value(),
+            ));
+            assert_eq!(Timestamp::now(), u64::max_value());
+        });
+    }
+
+    #[test]
+    fn median_timestamp_works() {
+        new_test_ext().execute_with(|| {
+            for now in vec![10, 20, 30, 40] {
+                assert_ok!(Timestamp::set(Origin::none(), now));
+                DidUpdate::<Test>::kill();
+                assert_eq!(Timestamp::median_timestamp(), None);
+            }
+
+            assert_ok!(Timestamp::set(Origin::none(), 50));
+            DidUpdate::<Test>::kill();
+            assert_eq!(Timestamp::median_timestamp(), Some(30));
+
+            // The oldest timestamp is overwritten.
+            assert_ok!(Timestamp::set(Origin::none(), 1000));
+            assert_eq!(RecentTimestamps::<Test>::get(), vec![1000, 20, 30, 40, 50]);
+            assert_eq!(Timestamp::median_timestamp(), Some(40));
+        });
+    }
+
+    #[test]
+    fn tuple_of_handlers_observes_timestamp() {
+        new_test_ext().execute_with(|| {
+            OBSERVED.with(|o| o.borrow_mut().clear());
+            assert_ok!(Timestamp::set(Origin::none(), 69));
+            assert_eq!(
+                OBSERVED.with(|o| o.borrow().clone()),
+                vec![("first", 69), ("second", 69)]
+            );
+        });
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Timestamp must increment by at least <MinimumPeriod> between sequential blocks"
+    )]
+    fn minimum_period_can_be_overridden() {
+        new_test_ext().execute_with(|| {
+            Timestamp::set_timestamp(42);
+            // Accepted once lowered below the configured `MinimumPeriod` of 5.
+            Timestamp::override_minimum_period(2);
+            assert_ok!(Timestamp::set(Origin::none(), 44));
+            DidUpdate::<Test>::kill();
+
+            // Rejected once raised above the increment.
+            Timestamp::override_minimum_period(10);
+            let _ = Timestamp::set(Origin::none(), 50);
+        });
+    }
+
+    #[test]
+    fn minimum_period_override_can_be_cleared() {
+        new_test_ext().execute_with(|| {
+            Timestamp::set_timestamp(40);
+            let data = inherent_data_with_timestamp(41);
+
+            Timestamp::override_minimum_period(10);
+            assert_eq!(
+                <Timestamp as ProvideInherent>::create_inherent(&data),
+                Some(crate::Call::set(50)),
+            );
+
+            // The configured `MinimumPeriod` of 5 applies again.
+            Timestamp::clear_minimum_period_override();
+            assert_eq!(
+                <Timestamp as ProvideInherent>::create_inherent(&data),
+                Some(crate::Call::set(45)),
+            );
+        });
+    }
+
+    #[test]
+    fn create_inherent_from_preset_data() {
+        new_test_ext().execute_with(|| {
+            let data = inherent_data_with_timestamp(100);
+            assert_eq!(
+                <Timestamp as ProvideInherent>::create_inherent(&data),
+                Some(crate::Call::set(100)),
+            );
+
+            // The inherent never goes below the minimum period.
+            Timestamp::set_timestamp(98);
+            assert_eq!(
+                <Timestamp as ProvideInherent>::create_inherent(&data),
+                Some(crate::Call::set(103)),
+            );
+        });
+    }
+
     #[test]
     #[should_panic(expected = "Timestamp must be updated only once in the block")]
     fn double_timestamp_should_fail() {
@@ -17,8 +17,8 @@
 
 //! Weights for pallet_timestamp
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 2.0.0
-//! DATE: 2020-10-27, STEPS: [50, ], REPEAT: 20, LOW RANGE: [], HIGH RANGE: []
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 128
+//! DATE: 2026-10-17, STEPS: [50, ], REPEAT: 20, LOW RANGE: [], HIGH RANGE: []
+//! EXECUTION: Some(Native), WASM-EXECUTION: Interpreted, CHAIN: Some("dev"), DB CACHE: 128
 
 // Executed Command:
 // target/release/substrate
@@ -28,8 +28,7 @@
 // --repeat=20
 // --pallet=pallet_timestamp
 // --extrinsic=*
-// --execution=wasm
-// --wasm-execution=compiled
+// --execution=native
 // --heap-pages=4096
 // --output=./frame/timestamp/src/weights.rs
 // --template=./.maintain/frame-weight-template.hbs
@@ -45,31 +44,35 @@ use sp_std::marker::PhantomData;
 
 /// Weight functions needed for pallet_timestamp.
 pub trait WeightInfo {
-    fn set() -> Weight;
+    fn set(w: u32) -> Weight;
     fn on_finalize() -> Weight;
 }
 
 /// Weights for pallet_timestamp using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-    fn set() -> Weight {
-        (11_650_000 as Weight)
-            .saturating_add(T::DbWeight::get().reads(2 as Weight))
-            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    fn set(w: u32) -> Weight {
+        (12_849_000 as Weight)
+            // Standard Error: 46_000
+            .saturating_add((36_000 as Weight).saturating_mul(w as Weight))
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(3 as Weight))
     }
     fn on_finalize() -> Weight {
-        (6_681_000 as Weight)
+        (3_711_000 as Weight)
     }
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
-    fn set() -> Weight {
-        (11_650_000 as Weight)
-            .saturating_add(RocksDbWeight::get().reads(2 as Weight))
-            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    fn set(w: u32) -> Weight {
+        (12_849_000 as Weight)
+            // Standard Error: 46_000
+            .saturating_add((36_000 as Weight).saturating_mul(w as Weight))
+            .saturating_add(RocksDbWeight::get().reads(4 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(3 as Weight))
     }
     fn on_finalize() -> Weight {
-        (6_681_000 as Weight)
+        (3_711_000 as Weight)
     }
 }
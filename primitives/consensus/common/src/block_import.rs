@@ -68,6 +68,21 @@ impl ImportResult {
 
         ImportResult::Imported(aux)
     }
+
+    /// Returns `true` if the block was imported.
+    pub fn is_imported(&self) -> bool {
+        matches!(self, ImportResult::Imported(_))
+    }
+
+    /// Returns `true` if the block was already in the blockchain.
+    pub fn is_known(&self) -> bool {
+        matches!(self, ImportResult::AlreadyInChain)
+    }
+
+    /// Returns `true` if the block or its parent is known to be bad.
+    pub fn is_bad(&self) -> bool {
+        matches!(self, ImportResult::KnownBad)
+    }
 }
 
 /// Block data origin.
@@ -185,6 +200,16 @@ impl<Block: BlockT, Transaction> BlockImportParams<Block, Transaction> {
         }
     }
 
+    /// Create block import params for a block that is already finalized.
+    ///
+    /// The block is finalized on import and always made the new best block.
+    pub fn finalized(origin: BlockOrigin, header: Block::Header) -> Self {
+        let mut params = Self::new(origin, header);
+        params.finalized = true;
+        params.fork_choice = Some(ForkChoiceStrategy::Custom(true));
+        params
+    }
+
     /// Get the full header hash (with post-digests applied).
     pub fn post_hash(&self) -> Block::Hash {
         if let Some(hash) = self.post_hash {
@@ -260,6 +285,57 @@ impl<Block: BlockT, Transaction> BlockImportParams<Block, Transaction> {
     }
 }
 
+/// Builder for [`BlockImportParams`].
+///
+/// Fields without a setter keep the defaults of [`BlockImportParams::new`].
+pub struct BlockImportParamsBuilder<Block: BlockT, Transaction> {
+    params: BlockImportParams<Block, Transaction>,
+}
+
+impl<Block: BlockT, Transaction> BlockImportParamsBuilder<Block, Transaction> {
+    /// Start building import params for the given origin and header.
+    pub fn new(origin: BlockOrigin, header: Block::Header) -> Self {
+        Self {
+            params: BlockImportParams::new(origin, header),
+        }
+    }
+
+    /// Set the justification provided for the block.
+    pub fn justification(mut self, justification: Option<Justification>) -> Self {
+        self.params.justification = justification;
+        self
+    }
+
+    /// Set the digest items that have been added after the runtime.
+    pub fn post_digests(mut self, post_digests: Vec<DigestItemFor<Block>>) -> Self {
+        self.params.post_digests = post_digests;
+        self
+    }
+
+    /// Set the body of the block.
+    pub fn body(mut self, body: Option<Vec<Block::Extrinsic>>) -> Self {
+        self.params.body = body;
+        self
+    }
+
+    /// Set whether the block is finalized already.
+    pub fn finalized(mut self, finalized: bool) -> Self {
+        self.params.finalized = finalized;
+        self
+    }
+
+    /// Set the fork choice strategy of this import.
+    pub fn fork_choice(mut self, fork_choice: ForkChoiceStrategy) -> Self {
+        self.params.fork_choice = Some(fork_choice);
+        self
+    }
+
+    /// Build the import params.
+    pub fn build(self) -> BlockImportParams<Block, Transaction> {
+        self.params
+    }
+}
+
 /// Block import trait.
 pub trait BlockImport<B: BlockT> {
     /// The error type.
@@ -323,6 +399,61 @@ where
     }
 }
 
+/// A [`BlockImport`] that logs every call before delegating it to the wrapped block import.
+///
+/// Results and errors of the inner block import are passed through unchanged.
+pub struct LoggingBlockImport<Inner> {
+    inner: Inner,
+}
+
+impl<Inner> LoggingBlockImport<Inner> {
+    /// Wrap the given block import.
+    pub fn new(inner: Inner) -> Self {
+        Self { inner }
+    }
+
+    /// Return the wrapped block import.
+    pub fn into_inner(self) -> Inner {
+        self.inner
+    }
+}
+
+impl<B: BlockT, Inner: BlockImport<B>> BlockImport<B> for LoggingBlockImport<Inner> {
+    type Error = Inner::Error;
+    type Transaction = Inner::Transaction;
+
+    fn check_block(&mut self, block: BlockCheckParams<B>) -> Result<ImportResult, Self::Error> {
+        let (hash, number) = (block.hash, block.number);
+        let result = self.inner.check_block(block);
+        log::debug!(
+            target: "block-import",
+            "check_block #{} ({:?}): {:?}",
+            number,
+            hash,
+            result,
+        );
+        result
+    }
+
+    fn import_block(
+        &mut self,
+        block: BlockImportParams<B, Self::Transaction>,
+        cache: HashMap<CacheKeyId, Vec<u8>>,
+    ) -> Result<ImportResult, Self::Error> {
+        let (hash, number, origin) = (block.post_hash(), *block.header.number(), block.origin);
+        let result = self.inner.import_block(block, cache);
+        log::debug!(
+            target: "block-import",
+            "import_block #{} ({:?}) from {:?}: {:?}",
+            number,
+            hash,
+            origin,
+            result,
+        );
+        result
+    }
+}
+
 /// Justification import trait
 pub trait JustificationImport<B: BlockT> {
     type Error: std::error::Error + Send + 'static;
@@ -341,3 +472,139 @@ pub trait JustificationImport<B: BlockT> {
         justification: Justification,
     ) -> Result<(), Self::Error>;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sp_runtime::generic::DigestItem;
+    use sp_test_primitives::{Block, Header};
+
+    #[test]
+    fn builder_matches_hand_constructed_params() {
+        let header = Header::new(
+            1,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        );
+        let justification: Justification = vec![1, 2, 3];
+        let post_digests = vec![DigestItem::Seal(*b"test", vec![4, 5])];
+
+        let mut expected = BlockImportParams::<Block, ()>::new(BlockOrigin::Own, header.clone());
+        expected.justification = Some(justification.clone());
+        expected.post_digests = post_digests.clone();
+        expected.body = Some(Vec::new());
+        expected.finalized = true;
+        expected.fork_choice = Some(ForkChoiceStrategy::LongestChain);
+
+        let built = BlockImportParamsBuilder::<Block, ()>::new(BlockOrigin::Own, header)
+            .justification(Some(justification))
+            .post_digests(post_digests)
+            .body(Some(Vec::new()))
+            .finalized(true)
+            .fork_choice(ForkChoiceStrategy::LongestChain)
+            .build();
+
+        assert_eq!(built.origin, expected.origin);
+        assert_eq!(built.header, expected.header);
+        assert_eq!(built.justification, expected.justification);
+        assert_eq!(built.post_digests, expected.post_digests);
+        assert_eq!(built.body, expected.body);
+        assert_eq!(built.finalized, expected.finalized);
+        assert_eq!(built.fork_choice, expected.fork_choice);
+        assert_eq!(built.post_hash(), expected.post_hash());
+    }
+
+    #[test]
+    fn finalized_params_are_final_and_best() {
+        let header = Header::new(
+            1,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        );
+
+        let params = BlockImportParams::<Block, ()>::finalized(BlockOrigin::File, header.clone());
+
+        assert_eq!(params.origin, BlockOrigin::File);
+        assert_eq!(params.header, header);
+        assert!(params.finalized);
+        assert_eq!(params.fork_choice, Some(ForkChoiceStrategy::Custom(true)));
+        assert_eq!(params.justification, None);
+        assert_eq!(params.body, None);
+    }
+
+    struct StubBlockImport;
+
+    impl BlockImport<Block> for StubBlockImport {
+        type Error = Error;
+        type Transaction = ();
+
+        fn check_block(
+            &mut self,
+            _block: BlockCheckParams<Block>,
+        ) -> Result<ImportResult, Self::Error> {
+            Ok(ImportResult::AlreadyInChain)
+        }
+
+        fn import_block(
+            &mut self,
+            _block: BlockImportParams<Block, ()>,
+            _cache: HashMap<CacheKeyId, Vec<u8>>,
+        ) -> Result<ImportResult, Self::Error> {
+            Err(Error::ClientImport("stub".into()))
+        }
+    }
+
+    #[test]
+    fn logging_block_import_passes_results_through() {
+        let header = Header::new(
+            1,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        );
+        let mut import = LoggingBlockImport::new(StubBlockImport);
+
+        let check = BlockCheckParams {
+            hash: header.hash(),
+            number: 1,
+            parent_hash: Default::default(),
+            allow_missing_state: false,
+            import_existing: false,
+        };
+        assert_eq!(
+            import.check_block(check).unwrap(),
+            ImportResult::AlreadyInChain
+        );
+
+        let params = BlockImportParams::<Block, ()>::new(BlockOrigin::Own, header);
+        match import.import_block(params, HashMap::new()) {
+            Err(Error::ClientImport(message)) => assert_eq!(message, "stub"),
+            result => panic!("unexpected result: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn import_result_classification() {
+        let cases = vec![
+            (ImportResult::imported(true), (true, false, false)),
+            (ImportResult::AlreadyInChain, (false, true, false)),
+            (ImportResult::KnownBad, (false, false, true)),
+            (ImportResult::UnknownParent, (false, false, false)),
+            (ImportResult::MissingState, (false, false, false)),
+        ];
+
+        for (result, expected) in cases {
+            assert_eq!(
+                (result.is_imported(), result.is_known(), result.is_bad()),
+                expected,
+                "{:?}",
+                result,
+            );
+        }
+    }
+}
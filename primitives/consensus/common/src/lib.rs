@@ -29,9 +29,13 @@
 #[macro_use]
 extern crate log;
 
-use std::sync::Arc;
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
 use std::time::Duration;
 
+use codec::Encode;
 use futures::prelude::*;
 pub use sp_inherents::InherentData;
 use sp_runtime::{
@@ -50,11 +54,11 @@ mod select_chain;
 
 pub use self::error::Error;
 pub use block_import::{
-    BlockCheckParams, BlockImport, BlockImportParams, BlockOrigin, ForkChoiceStrategy,
-    ImportResult, ImportedAux, JustificationImport,
+    BlockCheckParams, BlockImport, BlockImportParams, BlockImportParamsBuilder, BlockOrigin,
+    ForkChoiceStrategy, ImportResult, ImportedAux, JustificationImport, LoggingBlockImport,
 };
 pub use import_queue::DefaultImportQueue;
-pub use select_chain::SelectChain;
+pub use select_chain::{SelectChain, SelectChainExt};
 pub use sp_state_machine::Backend as StateBackend;
 
 /// Block status.
@@ -102,27 +106,52 @@ pub struct Proposal<Block: BlockT, Transaction> {
         sp_state_machine::StorageChanges<Transaction, HashFor<Block>, NumberFor<Block>>,
 }
 
+impl<Block: BlockT, Transaction> Proposal<Block, Transaction> {
+    /// Returns the size of the encoded block.
+    pub fn encoded_block_size(&self) -> usize {
+        self.block.encoded_size()
+    }
+
+    /// Returns the size of the encoded proof, if one was recorded.
+    pub fn proof_size(&self) -> Option<usize> {
+        self.proof.as_ref().map(|proof| proof.encoded_size())
+    }
+}
+
 /// Used as parameter to [`Proposer`] to tell the requirement on recording a proof.
 ///
 /// When `RecordProof::Yes` is given, all accessed trie nodes should be saved. These recorded
 /// trie nodes can be used by a third party to proof this proposal without having access to the
 /// full storage.
-#[derive(Copy, Clone, PartialEq)]
+///
+/// With `RecordProof::YesWithLimit` the proposal fails if the recorded proof exceeds the given
+/// number of bytes.
+#[derive(Copy, Clone, PartialEq, Debug)]
 pub enum RecordProof {
     /// `Yes`, record a proof.
     Yes,
+    /// `Yes`, record a proof of at most the given encoded size in bytes.
+    YesWithLimit(usize),
     /// `No`, don't record any proof.
     No,
 }
 
 impl RecordProof {
-    /// Returns if `Self` == `Yes`.
+    /// Returns if `Self` is `Yes` or `YesWithLimit`.
     pub fn yes(&self) -> bool {
         match self {
-            Self::Yes => true,
+            Self::Yes | Self::YesWithLimit(_) => true,
             Self::No => false,
         }
     }
+
+    /// Returns the maximum encoded size of the proof in bytes, if limited.
+    pub fn limit(&self) -> Option<usize> {
+        match self {
+            Self::YesWithLimit(limit) => Some(*limit),
+            Self::Yes | Self::No => None,
+        }
+    }
 }
 
 /// Will return [`RecordProof::No`] as default value.
@@ -175,6 +204,19 @@ pub trait Proposer<B: BlockT> {
         max_duration: Duration,
         record_proof: RecordProof,
     ) -> Self::Proposal;
+
+    /// Estimate the block weight consumed by inherents built from `inherent_data`.
+    ///
+    /// The returned value is in the runtime's weight units (picoseconds of execution
+    /// time on reference hardware) and is meant as a hint for callers that need to
+    /// know how much of the block is left for transactions before proposing.
+    /// Returns `0` by default, meaning the proposer gives no estimate.
+    ///
+    /// This method takes `&self` and has no type parameters, so it does not affect
+    /// whether the trait can be used as a trait object.
+    fn estimate_inherent_weight(&self, _inherent_data: &InherentData) -> u64 {
+        0
+    }
 }
 
 /// An oracle for when major synchronization work is being undertaken.
@@ -203,6 +245,40 @@ impl SyncOracle for NoNetwork {
     }
 }
 
+/// A synchronization oracle backed by shared atomic flags.
+///
+/// Clones share the same flags, so the state can be toggled at runtime from
+/// anywhere holding a handle. The first flag tracks major syncing, the second
+/// one tracks whether the network is offline.
+#[derive(Clone, Debug, Default)]
+pub struct AtomicSyncOracle(Arc<AtomicBool>, Arc<AtomicBool>);
+
+impl AtomicSyncOracle {
+    /// Create a new oracle that reports neither major syncing nor offline.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether the oracle reports major syncing.
+    pub fn set_major_syncing(&self, major_syncing: bool) {
+        self.0.store(major_syncing, Ordering::Relaxed);
+    }
+
+    /// Set whether the oracle reports being offline.
+    pub fn set_offline(&self, offline: bool) {
+        self.1.store(offline, Ordering::Relaxed);
+    }
+}
+
+impl SyncOracle for AtomicSyncOracle {
+    fn is_major_syncing(&mut self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+    fn is_offline(&mut self) -> bool {
+        self.1.load(Ordering::Relaxed)
+    }
+}
+
 impl<T> SyncOracle for Arc<T>
 where
     T: ?Sized,
@@ -275,6 +351,19 @@ impl<Block: BlockT> CanAuthorWith<Block> for NeverCanAuthor {
     }
 }
 
+/// Checks if the node can author blocks by calling the wrapped closure.
+#[derive(Clone)]
+pub struct CanAuthorWithFn<F>(pub F);
+
+impl<Block: BlockT, F> CanAuthorWith<Block> for CanAuthorWithFn<F>
+where
+    F: Fn(&BlockId<Block>) -> Result<(), String>,
+{
+    fn can_author_with(&self, at: &BlockId<Block>) -> Result<(), String> {
+        (self.0)(at)
+    }
+}
+
 /// A type from which a slot duration can be obtained.
 pub trait SlotData {
     /// Gets the slot duration.
@@ -291,3 +380,124 @@ impl SlotData for u64 {
 
     const SLOT_KEY: &'static [u8] = b"aura_slot_duration";
 }
+
+/// Computes the slot the given `timestamp_millis` falls into for slots of `slot_duration_millis`.
+///
+/// Returns `0` if the slot duration is zero.
+pub fn slot_from_timestamp(timestamp_millis: u64, slot_duration_millis: u64) -> u64 {
+    timestamp_millis
+        .checked_div(slot_duration_millis)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sp_runtime::traits::Header as _;
+    use sp_test_primitives::{Block, Header};
+
+    #[test]
+    fn atomic_sync_oracle_follows_flags() {
+        let handle = AtomicSyncOracle::new();
+        let mut oracle = handle.clone();
+        assert!(!oracle.is_major_syncing());
+        assert!(!oracle.is_offline());
+
+        handle.set_major_syncing(true);
+        assert!(oracle.is_major_syncing());
+        assert!(!oracle.is_offline());
+
+        handle.set_offline(true);
+        handle.set_major_syncing(false);
+        assert!(!oracle.is_major_syncing());
+        assert!(oracle.is_offline());
+    }
+
+    struct StubProposer;
+
+    impl Proposer<Block> for StubProposer {
+        type Error = Error;
+        type Transaction = ();
+        type Proposal = future::Ready<Result<Proposal<Block, ()>, Error>>;
+
+        fn propose(
+            self,
+            _: InherentData,
+            _: DigestFor<Block>,
+            _: Duration,
+            _: RecordProof,
+        ) -> Self::Proposal {
+            future::ready(Err(Error::CannotPropose))
+        }
+
+        fn estimate_inherent_weight(&self, inherent_data: &InherentData) -> u64 {
+            inherent_data.len() as u64 * 1_000
+        }
+    }
+
+    #[test]
+    fn proposer_can_override_inherent_weight_estimate() {
+        let mut inherent_data = InherentData::new();
+        assert_eq!(StubProposer.estimate_inherent_weight(&inherent_data), 0);
+
+        inherent_data.put_data(*b"testinh0", &42u64).unwrap();
+        assert_eq!(StubProposer.estimate_inherent_weight(&inherent_data), 1_000);
+    }
+
+    #[test]
+    fn proposal_reports_sizes() {
+        let header = Header::new(
+            1,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        );
+        let block = Block::new(header, Vec::new());
+        let mut proposal = Proposal::<Block, ()> {
+            block: block.clone(),
+            proof: None,
+            storage_changes: Default::default(),
+        };
+        assert_eq!(proposal.encoded_block_size(), block.encode().len());
+        assert_eq!(proposal.proof_size(), None);
+
+        let proof = sp_state_machine::StorageProof::new(vec![vec![1, 2, 3]]);
+        proposal.proof = Some(proof.clone());
+        assert_eq!(proposal.proof_size(), Some(proof.encode().len()));
+    }
+
+    #[test]
+    fn can_author_with_fn_calls_closure() {
+        let can_author = CanAuthorWithFn(|at: &BlockId<Block>| match at {
+            BlockId::Number(n) if n % 2 == 0 => Ok(()),
+            _ => Err("odd block".into()),
+        });
+
+        assert_eq!(can_author.can_author_with(&BlockId::Number(2)), Ok(()));
+        assert_eq!(
+            can_author.can_author_with(&BlockId::Number(3)),
+            Err("odd block".to_string()),
+        );
+    }
+
+    #[test]
+    fn record_proof_yes_and_limit() {
+        assert!(RecordProof::Yes.yes());
+        assert!(RecordProof::YesWithLimit(1024).yes());
+        assert!(!RecordProof::No.yes());
+
+        assert_eq!(RecordProof::Yes.limit(), None);
+        assert_eq!(RecordProof::YesWithLimit(1024).limit(), Some(1024));
+        assert_eq!(RecordProof::No.limit(), None);
+
+        assert_eq!(RecordProof::from(true), RecordProof::Yes);
+        assert_eq!(RecordProof::from(false), RecordProof::No);
+    }
+
+    #[test]
+    fn slot_from_timestamp_works() {
+        assert_eq!(slot_from_timestamp(12000, 6000), 2);
+        assert_eq!(slot_from_timestamp(12000, 0), 0);
+    }
+}
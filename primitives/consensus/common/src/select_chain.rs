@@ -16,7 +16,7 @@
 // limitations under the License.
 
 use crate::error::Error;
-use sp_runtime::traits::{Block as BlockT, NumberFor};
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT, NumberFor};
 
 /// The SelectChain trait defines the strategy upon which the head is chosen
 /// if multiple forks are present for an opaque definition of "best" in the
@@ -52,3 +52,80 @@ pub trait SelectChain<Block: BlockT>: Sync + Send + Clone {
         Ok(Some(target_hash))
     }
 }
+
+/// Convenience methods built on top of [`SelectChain`].
+pub trait SelectChainExt<Block: BlockT>: SelectChain<Block> {
+    /// Get the leaf to build upon among the leaves that can still be finalized.
+    ///
+    /// This is the head of the best chain if it is such a leaf, and the first leaf returned by
+    /// [`SelectChain::leaves`] otherwise.
+    fn best_finalized_descendant(&self) -> Result<<Block as BlockT>::Hash, Error> {
+        let leaves = self.leaves()?;
+        let best_hash = self.best_chain()?.hash();
+        if leaves.contains(&best_hash) {
+            return Ok(best_hash);
+        }
+        leaves
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::ChainLookup("No leaves that can be finalized".into()))
+    }
+}
+
+impl<Block: BlockT, T: SelectChain<Block>> SelectChainExt<Block> for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sp_test_primitives::{Block, Header};
+
+    #[derive(Clone)]
+    struct StubSelectChain {
+        leaves: Vec<<Block as BlockT>::Hash>,
+        best: Header,
+    }
+
+    impl SelectChain<Block> for StubSelectChain {
+        fn leaves(&self) -> Result<Vec<<Block as BlockT>::Hash>, Error> {
+            Ok(self.leaves.clone())
+        }
+
+        fn best_chain(&self) -> Result<Header, Error> {
+            Ok(self.best.clone())
+        }
+    }
+
+    fn header(number: u64) -> Header {
+        Header::new(
+            number,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        )
+    }
+
+    #[test]
+    fn best_finalized_descendant_prefers_best_chain() {
+        let best = header(2);
+        let other = header(1).hash();
+        let mut select_chain = StubSelectChain {
+            leaves: vec![other, best.hash()],
+            best: best.clone(),
+        };
+        assert_eq!(
+            select_chain.best_finalized_descendant().unwrap(),
+            best.hash()
+        );
+
+        // the best chain leads to a leaf that can't be finalized.
+        select_chain.leaves = vec![other];
+        assert_eq!(select_chain.best_finalized_descendant().unwrap(), other);
+
+        select_chain.leaves = Vec::new();
+        assert!(matches!(
+            select_chain.best_finalized_descendant(),
+            Err(Error::ChainLookup(_))
+        ));
+    }
+}
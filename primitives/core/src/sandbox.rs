@@ -84,6 +84,19 @@ pub const ERR_OUT_OF_BOUNDS: u32 = -2i32 as u32;
 /// For FFI purposes.
 pub const ERR_EXECUTION: u32 = -3i32 as u32;
 
+/// The limit of live sandboxed module instances has been reached and
+/// no new instance can be created until some of them are torn down.
+///
+/// For FFI purposes.
+pub const ERR_INSTANCE_LIMIT: u32 = -4i32 as u32;
+
+/// The maximum number of sandboxed module instances that can be alive at the same time during a
+/// single runtime call.
+///
+/// Every sandbox implementation enforces this limit, so whether an instantiation succeeds does not
+/// depend on the node or the executor running the runtime.
+pub const MAX_INSTANCES: u32 = 256;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -125,6 +125,13 @@ impl InherentData {
         self.data.insert(identifier, inherent.encode());
     }
 
+    /// Add all inherents of `other` to this instance.
+    ///
+    /// Data of `other` replaces any existing data for an inherent with the same identifier.
+    pub fn extend(&mut self, other: InherentData) {
+        self.data.extend(other.data);
+    }
+
     /// Returns the data for the requested inherent.
     ///
     /// # Return
@@ -490,6 +497,21 @@ mod tests {
         assert!(data.put_data(TEST_INHERENT_0, &10).is_err());
     }
 
+    #[test]
+    fn extend_replaces_existing_inherents() {
+        let mut data = InherentData::new();
+        data.put_data(TEST_INHERENT_0, &8u32).unwrap();
+
+        let mut other = InherentData::new();
+        other.put_data(TEST_INHERENT_0, &9u32).unwrap();
+        other.put_data(TEST_INHERENT_1, &10u32).unwrap();
+        data.extend(other);
+
+        assert_eq!(data.len(), 2);
+        assert_eq!(data.get_data::<u32>(&TEST_INHERENT_0).unwrap(), Some(9));
+        assert_eq!(data.get_data::<u32>(&TEST_INHERENT_1).unwrap(), Some(10));
+    }
+
     #[derive(Clone)]
     struct TestInherentDataProvider {
         registered: Arc<RwLock<bool>>,
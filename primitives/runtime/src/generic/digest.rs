@@ -58,6 +58,20 @@ impl<Hash> Digest<Hash> {
         self.logs.push(item);
     }
 
+    /// Push new digest item, unless an equal item is already present.
+    ///
+    /// Returns `true` if the item was added.
+    pub fn push_unique(&mut self, item: DigestItem<Hash>) -> bool
+    where
+        Hash: PartialEq,
+    {
+        if self.logs.contains(&item) {
+            return false;
+        }
+        self.logs.push(item);
+        true
+    }
+
     /// Pop a digest item.
     pub fn pop(&mut self) -> Option<DigestItem<Hash>> {
         self.logs.pop()
@@ -78,6 +92,28 @@ impl<Hash> Digest<Hash> {
     ) -> Option<T> {
         self.logs().iter().filter_map(predicate).next()
     }
+
+    /// Get the payloads of all `PreRuntime` digest items with the given engine id, in order.
+    pub fn pre_runtime_items(&self, id: &ConsensusEngineId) -> Vec<&[u8]> {
+        self.logs()
+            .iter()
+            .filter_map(|item| match item.as_pre_runtime() {
+                Some((engine_id, data)) if engine_id == *id => Some(data),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Get the payloads of all `Consensus` digest items with the given engine id, in order.
+    pub fn consensus_items(&self, id: &ConsensusEngineId) -> Vec<&[u8]> {
+        self.logs()
+            .iter()
+            .filter_map(|item| match item.as_consensus() {
+                Some((engine_id, data)) if engine_id == *id => Some(data),
+                _ => None,
+            })
+            .collect()
+    }
 }
 
 /// Digest item that is able to encode/decode 'system' digest items and
@@ -273,6 +309,17 @@ impl<Hash> DigestItem<Hash> {
     pub fn try_to<T: Decode>(&self, id: OpaqueDigestItemId) -> Option<T> {
         self.dref().try_to::<T>(id)
     }
+
+    /// Returns `true` if both entries are `PreRuntime`, `Consensus` or `Seal` entries of the
+    /// same kind with equal consensus engine ids, regardless of their payloads.
+    pub fn same_engine_as(&self, other: &Self) -> bool {
+        match (self, other) {
+            (DigestItem::PreRuntime(a, _), DigestItem::PreRuntime(b, _))
+            | (DigestItem::Consensus(a, _), DigestItem::Consensus(b, _))
+            | (DigestItem::Seal(a, _), DigestItem::Seal(b, _)) => a == b,
+            _ => false,
+        }
+    }
 }
 
 impl<Hash: Encode> Encode for DigestItem<Hash> {
@@ -455,4 +502,57 @@ mod tests {
             r#"{"logs":["0x0204000000","0x000c010203","0x05746573740c010203"]}"#
         );
     }
+
+    #[test]
+    fn should_return_all_matching_pre_runtime_items() {
+        let digest: Digest<u32> = Digest {
+            logs: vec![
+                DigestItem::PreRuntime(*b"aura", vec![1, 2]),
+                DigestItem::PreRuntime(*b"babe", vec![3, 4]),
+                DigestItem::PreRuntime(*b"aura", vec![5]),
+            ],
+        };
+
+        assert_eq!(
+            digest.pre_runtime_items(b"aura"),
+            vec![&[1u8, 2][..], &[5u8][..]],
+        );
+    }
+
+    #[test]
+    fn same_engine_as_compares_engine_ids() {
+        let aura_seal: DigestItem<u32> = DigestItem::Seal(*b"aura", vec![1, 2]);
+
+        assert!(aura_seal.same_engine_as(&DigestItem::Seal(*b"aura", vec![3])));
+        assert!(!aura_seal.same_engine_as(&DigestItem::Seal(*b"babe", vec![1, 2])));
+        // only items of the same kind target the same engine.
+        assert!(!aura_seal.same_engine_as(&DigestItem::PreRuntime(*b"aura", vec![1, 2])));
+        assert!(!aura_seal.same_engine_as(&DigestItem::Other(vec![1, 2])));
+    }
+
+    #[test]
+    fn should_return_all_matching_consensus_items() {
+        let digest: Digest<u32> = Digest {
+            logs: vec![
+                DigestItem::Consensus(*b"aura", vec![1, 2]),
+                DigestItem::PreRuntime(*b"aura", vec![3, 4]),
+                DigestItem::Consensus(*b"babe", vec![5]),
+                DigestItem::Consensus(*b"aura", vec![6]),
+            ],
+        };
+
+        assert_eq!(
+            digest.consensus_items(b"aura"),
+            vec![&[1u8, 2][..], &[6u8][..]],
+        );
+    }
+
+    #[test]
+    fn push_unique_skips_duplicate_items() {
+        let mut digest: Digest<u32> = Digest::default();
+
+        assert!(digest.push_unique(DigestItem::Seal(*b"test", vec![1, 2, 3])));
+        assert!(!digest.push_unique(DigestItem::Seal(*b"test", vec![1, 2, 3])));
+        assert_eq!(digest.logs().len(), 1);
+    }
 }
@@ -64,6 +64,10 @@ pub enum Error {
 
     /// Failed to invoke the start function or an exported function for some reason.
     Execution,
+
+    /// Another instance can't be created because [`sp_core::sandbox::MAX_INSTANCES`] sandbox
+    /// instances are already alive.
+    InstanceLimit,
 }
 
 impl From<Error> for HostError {
@@ -173,7 +177,8 @@ impl<T> Instance<T> {
     ///
     /// Returns `Err(Error::Module)` if this module can't be instantiated with the given
     /// environment. If execution of `start` function generated a trap, then `Err(Error::Execution)` will
-    /// be returned.
+    /// be returned. If `sp_core::sandbox::MAX_INSTANCES` sandbox instances are already alive,
+    /// `Err(Error::InstanceLimit)` is returned.
     ///
     /// [`EnvironmentDefinitionBuilder`]: struct.EnvironmentDefinitionBuilder.html
     pub fn new(
@@ -24,8 +24,14 @@ use wasmi::{
 	RuntimeArgs, RuntimeValue, Signature, TableDescriptor, TableRef, Trap, TrapKind
 };
 use wasmi::memory_units::Pages;
+use sp_core::sandbox as sandbox_primitives;
 use super::{Error, Value, ReturnValue, HostFuncType, HostError};
 
+std::thread_local! {
+	/// Number of sandbox instances alive on the current thread.
+	static LIVE_INSTANCES: std::cell::Cell<u32> = std::cell::Cell::new(0);
+}
+
 #[derive(Clone)]
 pub struct Memory {
 	memref: MemoryRef,
@@ -243,6 +249,11 @@ impl<T> Instance<T> {
 		env_def_builder: &EnvironmentDefinitionBuilder<T>,
 		state: &mut T,
 	) -> Result<Instance<T>, Error> {
+		// Enforce the same limit as the wasm executors, so that native execution agrees with them.
+		if LIVE_INSTANCES.with(|count| count.get()) >= sandbox_primitives::MAX_INSTANCES {
+			return Err(Error::InstanceLimit);
+		}
+
 		let module = Module::from_buffer(code).map_err(|_| Error::Module)?;
 		let not_started_instance = ModuleInstance::new(&module, env_def_builder)
 			.map_err(|_| Error::Module)?;
@@ -259,6 +270,8 @@ impl<T> Instance<T> {
 			instance
 		};
 
+		LIVE_INSTANCES.with(|count| count.set(count.get() + 1));
+
 		Ok(Instance {
 			instance,
 			defined_host_functions,
@@ -298,9 +311,16 @@ impl<T> Instance<T> {
 	}
 }
 
+impl<T> Drop for Instance<T> {
+	fn drop(&mut self) {
+		LIVE_INSTANCES.with(|count| count.set(count.get().saturating_sub(1)));
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use crate::{Error, Value, ReturnValue, HostError, EnvironmentDefinitionBuilder, Instance};
+	use super::sandbox_primitives;
 	use assert_matches::assert_matches;
 
 	fn execute_sandboxed(code: &[u8], args: &[Value]) -> Result<ReturnValue, HostError> {
@@ -471,4 +491,27 @@ mod tests {
 			Err(Error::Execution)
 		);
 	}
+
+	#[test]
+	fn instance_limit() {
+		let code = wat::parse_str(r#"
+		(module
+			(func (export "call")
+			)
+		)
+		"#).unwrap();
+		let env_builder = EnvironmentDefinitionBuilder::<()>::new();
+
+		let mut instances = (0..sandbox_primitives::MAX_INSTANCES)
+			.map(|_| Instance::new(&code, &env_builder, &mut ()).unwrap())
+			.collect::<Vec<_>>();
+		assert_matches!(
+			Instance::new(&code, &env_builder, &mut ()).err(),
+			Some(Error::InstanceLimit)
+		);
+
+		// Tearing down an instance makes room for another one.
+		instances.pop();
+		assert!(Instance::new(&code, &env_builder, &mut ()).is_ok());
+	}
 }
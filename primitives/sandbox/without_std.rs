@@ -230,6 +230,7 @@ impl<T> Instance<T> {
 		let instance_idx = match result {
 			sandbox_primitives::ERR_MODULE => return Err(Error::Module),
 			sandbox_primitives::ERR_EXECUTION => return Err(Error::Execution),
+			sandbox_primitives::ERR_INSTANCE_LIMIT => return Err(Error::InstanceLimit),
 			instance_idx => instance_idx,
 		};
 
@@ -109,6 +109,8 @@ impl ProvideInherentData for InherentDataProvider {
 }
 
 /// A trait which is called when the timestamp is set.
+///
+/// Implemented for tuples of handlers, which are called in order.
 #[impl_trait_for_tuples::impl_for_tuples(30)]
 pub trait OnTimestampSet<Moment> {
     fn on_timestamp_set(moment: Moment);
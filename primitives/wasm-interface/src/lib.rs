@@ -333,6 +333,8 @@ pub trait Sandbox {
     ) -> Result<u32>;
     /// Delete a sandbox instance.
     fn instance_teardown(&mut self, instance_id: u32) -> Result<()>;
+    /// Returns the number of sandbox instances that have not been torn down yet.
+    fn instance_count(&self) -> u32;
     /// Create a new sandbox instance.
     fn instance_new(
         &mut self,